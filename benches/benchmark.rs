@@ -1,91 +1,64 @@
-#![allow(unstable_features)]
-extern crate test;
+//! Benchmarks every registered solution that has an input file.
+//!
+//! Runs on stable Rust without the unstable `test` crate. Any non flag arguments filter
+//! benchmarks by name, for example `cargo bench year2023::day01`. Inputs are read from the
+//! `AOC_INPUT_DIR` environment variable or `--input-dir <dir>` in the same way as the runner.
+use aoc::solution::{input_dir, solutions, Entry};
+use std::env::args;
+use std::fs::read_to_string;
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
-macro_rules! benchmark {
-    ($year:tt $($day:tt),*) => {
-        mod $year {$(
-            mod $day {
-                use aoc::$year::$day::*;
-                use std::fs::read_to_string;
-                use std::path::Path;
-                use std::sync::LazyLock;
-                use test::Bencher;
+/// Each phase is repeated until at least this much time has elapsed.
+const TARGET: Duration = Duration::from_millis(500);
 
-                static DATA: LazyLock<String> = LazyLock::new(|| {
-                    let year = stringify!($year);
-                    let day = stringify!($day);
-                    let path = Path::new("input").join(year).join(day).with_extension("txt");
-                    read_to_string(path).unwrap()
-                });
+fn main() {
+    let mut args = args().skip(1);
+    let mut dir = input_dir();
+    let mut filters = Vec::new();
 
-                #[bench]
-                fn parse_bench(b: &mut Bencher) {
-                    let data = &DATA;
-                    b.iter(|| parse(data));
-                }
+    while let Some(arg) = args.next() {
+        if arg == "--input-dir" {
+            dir = args.next().map(PathBuf::from).unwrap_or(dir);
+        } else if !arg.starts_with("--") {
+            filters.push(arg);
+        }
+    }
+
+    for solution in solutions() {
+        let Entry { year, day, .. } = solution;
+        let name = format!("year{year}::day{day:02}");
 
-                #[bench]
-                fn part1_bench(b: &mut Bencher) {
-                    let input = parse(&DATA);
-                    b.iter(|| part1(&input));
-                }
+        if !filters.is_empty() && !filters.iter().any(|filter| name.contains(filter.as_str())) {
+            continue;
+        }
+        let Ok(data) = read_to_string(solution.path_in(&dir)) else {
+            continue;
+        };
 
-                #[bench]
-                fn part2_bench(b: &mut Bencher) {
-                    let input = parse(&DATA);
-                    b.iter(|| part2(&input));
-                }
+        let input = match (solution.parse)(&data) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("Skipping {name}, {error}");
+                continue;
             }
-        )*}
+        };
+        bench(&name, "parse_bench", || (solution.parse)(&data));
+        bench(&name, "part1_bench", || (solution.part1)(input.as_ref()));
+        bench(&name, "part2_bench", || (solution.part2)(input.as_ref()));
     }
 }
 
-// benchmark!(year2015
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-// benchmark!(year2016
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-// benchmark!(year2017
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-// benchmark!(year2018
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-benchmark!(year2019
-    day01
-    // day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-);
-//
-// benchmark!(year2020
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-// benchmark!(year2021
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-// benchmark!(year2022
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-// benchmark!(year2023
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
+fn bench<T>(name: &str, phase: &str, mut f: impl FnMut() -> T) {
+    let mut iterations = 0;
+    let instant = Instant::now();
 
-// benchmark!(year2024
-//     day01
-//     // day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20
-// );
+    while instant.elapsed() < TARGET {
+        black_box(f());
+        iterations += 1;
+    }
+
+    let nanos = instant.elapsed().as_nanos() / iterations;
+    println!("test {name}::{phase} ... bench: {nanos:>12} ns/iter ({iterations} iterations)");
+}
@@ -0,0 +1,100 @@
+//! Error for puzzle input that doesn't match the expected format.
+//!
+//! Parsers report where the problem was found, so that instead of a panic and a backtrace the
+//! runner can point at the offending line and column:
+//!
+//! ```none
+//! line 2, column 5: Expected a number, found "x"
+//!   |
+//! 2 | 1,2,x,4
+//!   |     ^
+//! ```
+//!
+//! Errors raised without the whole input to hand, for example by
+//! [`ParseOps::try_unsigned`](crate::util::parse::ParseOps::try_unsigned) on a single token,
+//! can be placed afterwards with [`AocError::locate`].
+use std::error::Error;
+use std::fmt;
+
+pub type AocResult<T> = Result<T, AocError>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AocError {
+    pub message: String,
+    /// One based line number, when known.
+    pub line: Option<usize>,
+    /// One based column counted in characters, when known.
+    pub column: Option<usize>,
+    /// Full text of the offending line.
+    pub context: Option<String>,
+}
+
+impl AocError {
+    /// Error without a location.
+    pub fn new(message: impl Into<String>) -> Self {
+        AocError { message: message.into(), line: None, column: None, context: None }
+    }
+
+    /// Error at byte `offset` of `input`.
+    pub fn at(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        AocError::new(message).place(input, offset)
+    }
+
+    /// Error at the start of `fragment`, which should be a slice of `input`. Any other string
+    /// leaves the error without a location.
+    pub fn within(input: &str, fragment: &str, message: impl Into<String>) -> Self {
+        AocError::new(message).locate(input, fragment)
+    }
+
+    /// Fills in the location from `fragment`, a slice of `input`, unless already known.
+    pub fn locate(self, input: &str, fragment: &str) -> Self {
+        if self.line.is_some() {
+            return self;
+        }
+        match offset(input, fragment) {
+            Some(offset) => self.place(input, offset),
+            None => self,
+        }
+    }
+
+    fn place(mut self, input: &str, offset: usize) -> Self {
+        let mut offset = offset.min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+
+        self.line = Some(input[..start].matches('\n').count() + 1);
+        self.column = Some(input[start..offset].chars().count() + 1);
+        self.context = Some(input[start..end].trim_end_matches('\r').to_owned());
+        self
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (Some(line), Some(column)) = (self.line, self.column) else {
+            return f.write_str(&self.message);
+        };
+        write!(f, "line {line}, column {column}: {}", self.message)?;
+
+        if let Some(context) = &self.context {
+            let width = line.to_string().len();
+            write!(f, "\n{:width$} |", "")?;
+            write!(f, "\n{line} | {context}")?;
+            write!(f, "\n{:width$} | {:>column$}", "", "^")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for AocError {}
+
+/// Byte offset of `fragment` within `input`, if it really is a slice of it.
+fn offset(input: &str, fragment: &str) -> Option<usize> {
+    let start = input.as_ptr() as usize;
+    let at = fragment.as_ptr() as usize;
+    (start <= at && at + fragment.len() <= start + input.len()).then(|| at - start)
+}
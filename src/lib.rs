@@ -4,6 +4,16 @@
 //!
 //! [badge]: https://img.shields.io/badge/github-blue?style=for-the-badge&logo=github&labelColor=grey
 //! [link]: https://github.com/maneatingape/advent-of-code-rust
+//!
+//! Any registered day can be solved with [`solve`], returning both answers as an
+//! [`Answer`](solution::Answer) whatever type the day itself uses, while [`available`] lists
+//! the days that have a solution:
+//!
+//! ```
+//! let (part1, part2) = aoc::solve(2019, 1, "100756\n").unwrap();
+//! assert_eq!((part1.to_string(), part2.to_string()), ("33583".into(), "50346".into()));
+//! assert!(aoc::available().any(|(year, day)| (year, day) == (2019, 1)));
+//! ```
 
 // Portable SIMD API is enabled by "simd" feature.
 // #![cfg_attr(feature = "simd", allow(unstable_features), feature(portable_simd))]
@@ -17,68 +27,140 @@ macro_rules! library {
     }
 }
 
+/// Declares each year's modules and registers every day as a [`solution::Solution`].
+/// Helper modules that are not puzzles, such as the 2019 Intcode computer, follow `with`.
+///
+/// By default both parts receive the raw input. Days marked `: parse` instead export an `Input`
+/// type and a `parse` function that is called once and timed separately, with both parts
+/// receiving a reference to the parsed `Input`. Days marked `: try_parse` export a `try_parse`
+/// function returning an [`error::AocResult`], so that input in the wrong format is reported
+/// with its line and column instead of panicking.
+macro_rules! solutions {
+    ($($year:tt $description:literal $($day:tt $(: $parse:ident)?),* $(with $($module:tt),*)?;)*) => {
+        $(
+            #[doc = concat!("# ", $description)]
+            pub mod $year {$(pub mod $day;)* $($(pub mod $module;)*)?}
+
+            $(solution!($year $day $($parse)?);)*
+        )*
+
+        const SOLUTIONS: &[solution::Entry] = &[$($(
+            solution::Entry::new::<solution::Puzzle<
+                { solution::number(stringify!($year)) },
+                { solution::number(stringify!($day)) },
+            >>(),
+        )*)*];
+    }
+}
+
+macro_rules! solution {
+    ($year:tt $day:tt) => {
+        solution!($year $day String, |input: &str| Ok(input.to_owned()));
+    };
+    ($year:tt $day:tt parse) => {
+        solution!($year $day $year::$day::Input, |input| Ok($year::$day::parse(input)));
+    };
+    ($year:tt $day:tt try_parse) => {
+        solution!($year $day $year::$day::Input, $year::$day::try_parse);
+    };
+    ($year:tt $day:tt $input:ty, $parse:expr) => {
+        impl solution::Solution for solution::Puzzle<
+            { solution::number(stringify!($year)) },
+            { solution::number(stringify!($day)) },
+        > {
+            const YEAR: u32 = solution::number(stringify!($year));
+            const DAY: u32 = solution::number(stringify!($day));
+
+            type Input = $input;
+
+            fn parse(input: &str) -> error::AocResult<$input> {
+                $parse(input)
+            }
+
+            fn part1(input: &$input) -> solution::Answer {
+                $year::$day::part1(input).into()
+            }
+
+            fn part2(input: &$input) -> solution::Answer {
+                $year::$day::part2(input).into()
+            }
+        }
+    };
+}
+
+pub mod error;
+pub mod scaffold;
+pub mod solution;
+
+pub use solution::{available, solve};
+
 library!(util "Utility modules to handle common recurring Advent of Code patterns."
     ansi,
-    // bitset, grid, hash, heap,
+    // bitset,
+    grid,
+    // hash,
+    heap,
      integer,
-    // iter, math, md5,
+    // iter,
+    math,
+    md5,
     parse,
-    point
+    point,
+    point3
     // , point, slice, thread
 );
 
-// library!(year2015 "Help Santa by solving puzzles to fix the weather machine's snow function."
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-// library!(year2016 "Defeat the Easter Bunny to save Christmas."
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-// library!(year2017 "A technical support callout from the Elves escalates rapidly."
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-// library!(year2018 "Travel through time to restore the festive timeline."
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-library!(year2019 "Rescue Santa from deep space with a solar system voyage."
-    day01, day02, day03, day04,day05, day06, intcode
-
-    // day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25, intcode
-);
-//
-// library!(year2020 "What could go wrong trying to enjoy a well deserved vacation?"
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-// library!(year2021 "Retrieve the keys to Santa's sleigh with an underwater submarine adventure."
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-// library!(year2022 "Assist the Elves on their annual jungle expedition."
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-library!(year2023 "Restore global snow production."
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24
-);
-//
-library!(year2024 "Locate the Chief Historian in time for the big Christmas sleigh launch."
-    day01,
-    day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+library!(runner "Run solutions against their inputs and report the results."
+    answers, format, history, input, isolate, jobs, memory, record, report, select, serve,
+    stats
 );
 
-library!(year2025 "Advent of Code 2025"
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12
+library!(site "Download inputs from and submit answers to the Advent of Code website."
+    client, fetch, http, submit
 );
+
+solutions! {
+    // year2015 "Help Santa by solving puzzles to fix the weather machine's snow function."
+    //     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    //     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25;
+    //
+    // year2016 "Defeat the Easter Bunny to save Christmas."
+    //     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    //     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25;
+    //
+    // year2017 "A technical support callout from the Elves escalates rapidly."
+    //     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    //     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25;
+    //
+    // year2018 "Travel through time to restore the festive timeline."
+    //     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    //     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25;
+    //
+    year2019 "Rescue Santa from deep space with a solar system voyage."
+        day01: try_parse, day02: try_parse, day03, day04, day05: try_parse, day06
+        with intcode;
+    //
+    // year2020 "What could go wrong trying to enjoy a well deserved vacation?"
+    //     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    //     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25;
+    //
+    // year2021 "Retrieve the keys to Santa's sleigh with an underwater submarine adventure."
+    //     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    //     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25;
+    //
+    // year2022 "Assist the Elves on their annual jungle expedition."
+    //     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    //     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25;
+    //
+    year2023 "Restore global snow production."
+        day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+        day14, day15, day16, day17, day18, day19: try_parse, day20, day21, day22, day23, day24;
+    //
+    year2024 "Locate the Chief Historian in time for the big Christmas sleigh launch."
+        day01: parse, day02, day03, day04, day05, day06, day07, day08, day09: parse, day10, day11,
+        day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22,
+        day23: parse, day24, day25;
+
+    year2025 "Advent of Code 2025"
+        day01, day02, day03, day04, day05, day06, day07, day08, day09: parse, day10, day11, day12;
+}
@@ -1,170 +1,732 @@
+use aoc::runner::answers::{self, Expected};
+use aoc::runner::format::{self as format, Format, Printer};
+use aoc::runner::history::{self, Timing};
+use aoc::runner::isolate;
+use aoc::runner::jobs;
+use aoc::runner::record::Status;
+use aoc::runner::report::{self, Row};
+use aoc::runner::select::Selection;
+use aoc::runner::serve;
+use aoc::runner::stats::Sampling;
+use aoc::scaffold;
+use aoc::site;
+use aoc::site::client::{session, Client, DEFAULT_BASE_URL, DEFAULT_DELAY};
+use aoc::site::fetch::{calendar, unlocked};
+use aoc::site::submit::Reply;
+use aoc::solution::{input_dir, input_path, solutions, Answer, Entry};
 use aoc::util::ansi::*;
-use aoc::util::parse::*;
-use aoc::*;
 use std::env::args;
-use std::fs::read_to_string;
-use std::iter::empty;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io::{self, read_to_string as read_all, stdin, Write};
+use std::net::TcpListener;
 use std::path::{Path, PathBuf};
-use std::time::{Duration, Instant};
+use std::process::exit;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Counts heap allocations when built with the `memory` feature, see [`aoc::runner::memory`].
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOCATOR: aoc::runner::memory::Counting = aoc::runner::memory::Counting;
+
+/// Generous enough for every solution in debug builds, while still catching infinite loops.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+/// Percentage slowdown tolerated by `compare`, allowing for some noise between runs.
+const DEFAULT_THRESHOLD: f64 = 10.0;
 
 fn main() {
+    let mut args = args().skip(1).peekable();
+
+    match args.peek().map(String::as_str) {
+        Some("fetch") => fetch(args.skip(1)),
+        Some("submit") => submit(args.skip(1)),
+        Some("new") => new(args.skip(1)),
+        Some("serve") => serve(args.skip(1)),
+        Some("compare") => compare(args.skip(1)),
+        Some("report") => report(args.skip(1)),
+        _ => run(args),
+    }
+}
+
+/// Runs the selected solutions, printing their answers and timings.
+fn run(args: impl Iterator<Item = String>) {
     // Parse command line options
-    let (year, day) = match args().nth(1) {
-        Some(arg) => {
-            let str = arg.as_str();
-            let mut iter = str.iter_unsigned();
-            (iter.next(), iter.next())
-        }
-        None => (None, None),
-    };
+    let options = Options::parse(args).unwrap_or_else(|message| usage(&message));
+    let dir = options.input_dir.clone().unwrap_or_else(input_dir);
 
     // Filter solutions
-    let solutions: Vec<_> = empty()
-        // .chain(year2015())
-        // .chain(year2016())
-        // .chain(year2017())
-        // .chain(year2018())
-        .chain(year2019())
-        // .chain(year2020())
-        // .chain(year2021())
-        // .chain(year2022())
-        .chain(year2023())
-        .chain(year2024())
-        .chain(year2025())
-        .filter(|solution| year.is_none_or(|y: u32| y == solution.year))
-        .filter(|solution| day.is_none_or(|d: u32| d == solution.day))
+    let solutions: Vec<_> = solutions()
+        .iter()
+        .filter(|solution| options.selection.matches(solution.year, solution.day))
+        .filter(|solution| !options.with_input || solution.path_in(&dir).exists())
         .collect();
 
-    // Pretty print output for each solution.
+    if solutions.is_empty() && !options.selection.is_empty() {
+        usage("No solutions match the selection");
+    }
+
+    // An explicit input file or stdin only makes sense for a single day.
+    let input = options.input.as_deref().map(|input| {
+        if solutions.len() != 1 {
+            usage(&format!("--input needs exactly one day, found {} days", solutions.len()));
+        }
+        read_input(input).unwrap_or_else(|message| usage(&message))
+    });
+    let run = |solution: &&'static Entry| match &input {
+        Some((path, data)) => {
+            isolate::solve(solution, path.clone(), data.clone(), options.sampling, options.timeout)
+        }
+        None => isolate::run(solution, solution.path_in(&dir), options.sampling, options.timeout),
+    };
+
+    // Timings are only recorded for history when asked, as finding the revision runs git.
+    let key = options.history.as_ref().map(|_| (history::revision(), options.machine.clone()));
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    let mut timings = Vec::new();
+
+    // Print output for each solution in the requested format, keeping anything the solutions
+    // print themselves out of machine readable records.
+    let out: Box<dyn Write> = match options.format {
+        Format::Text => Box::new(io::stdout()),
+        Format::Json | Format::Csv => take_stdout(),
+    };
+    let mut printer = Printer::new(options.format, out);
+    let mut stars = 0;
     let mut duration = Duration::ZERO;
+    let mut wrong = 0;
+    let mut failed = 0;
 
-    for Solution { year, day, path, wrapper } in &solutions {
-        if let Ok(data) = read_to_string(path) {
-            println!("{BOLD}{GREEN}{year} Day {day:02}{RESET}");
-            let instant = Instant::now();
-            let (part1, part2, part1_time, part2_time) = wrapper(data);
-            duration += instant.elapsed();
-
-            // Display part1_time
-            // round to 1 decimal place
-            if part1_time > 1000 {
-                println!("    Part 1: {part1} ({:.1} ms)", part1_time as f32 / 1000f32);
-            } else {
-                println!("    Part 1: {part1} ({} µs)", part1_time);
+    printer.begin();
+    let instant = Instant::now();
+    let result = jobs::run(&solutions, options.jobs, run, |solution, mut record| {
+        if options.verify {
+            record.verify(&Expected::load(&dir, solution));
+            wrong += record.is_wrong() as usize;
+        }
+        match record.status {
+            Status::Ok => {
+                stars += 2;
+                duration += record.total_time();
+            }
+            Status::InvalidInput | Status::Panicked | Status::Timeout => failed += 1,
+            Status::MissingInput => (),
+        }
+        if let Some((revision, machine)) = &key {
+            timings.extend(Timing::new(&record, revision, machine, now));
+        }
+        printer.record(&record);
+    });
+    let elapsed = instant.elapsed();
+    printer.end();
+
+    if let Err(message) = result {
+        usage(&format!("Unable to start jobs: {message}"));
+    }
+    if let Some(path) = &options.history {
+        if let Err(message) = history::append(path, &timings) {
+            eprintln!("{BOLD}{RED}{message}{RESET}");
+            failed += 1;
+        }
+    }
+
+    // Optionally print totals.
+    if options.totals && options.format == Format::Text {
+        println!("{BOLD}{YELLOW}⭐ {stars}{RESET}");
+        println!(
+            "{BOLD}{WHITE}🕓 {} ms ({} ms wall time){RESET}",
+            duration.as_millis(),
+            elapsed.as_millis()
+        );
+    }
+
+    // Any mismatch when verifying or solution that failed to finish fails the run.
+    if failed > 0 {
+        eprintln!(
+            "{BOLD}{RED}{failed} solution(s) had invalid input, panicked or timed out{RESET}"
+        );
+    }
+    if wrong > 0 {
+        eprintln!("{BOLD}{RED}{wrong} solution(s) gave a wrong answer{RESET}");
+    }
+    if failed > 0 || wrong > 0 {
+        exit(1);
+    }
+}
+
+#[derive(Default)]
+struct Options {
+    selection: Selection,
+    with_input: bool,
+    format: Format,
+    totals: bool,
+    verify: bool,
+    sampling: Sampling,
+    timeout: Option<Duration>,
+    jobs: usize,
+    input_dir: Option<PathBuf>,
+    input: Option<String>,
+    history: Option<PathBuf>,
+    machine: String,
+}
+
+impl Options {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Options { timeout: Some(DEFAULT_TIMEOUT), jobs: 1, ..Options::default() };
+        let mut machine = None;
+        let mut terms = Vec::new();
+
+        parse_args(args, |flag, value| {
+            match flag {
+                "--totals" => options.totals = true,
+                "--with-input" => options.with_input = true,
+                "--verify" => options.verify = true,
+                "--warmup" => options.sampling.warmup = number(flag, &value()?)?,
+                "--iterations" => match number(flag, &value()?)? {
+                    0 => return Err("--iterations must be at least 1".to_owned()),
+                    n => options.sampling.iterations = n,
+                },
+                "--format" => options.format = value()?.parse()?,
+                "--input-dir" => options.input_dir = Some(value()?.into()),
+                "--input" => options.input = Some(value()?),
+                "--jobs" => options.jobs = number(flag, &value()?)?,
+                "--timeout" => options.timeout = seconds(flag, &value()?)?,
+                "--history" => options.history = Some(value()?.into()),
+                "--machine" => machine = Some(history::sanitize(&value()?)),
+                _ if flag.starts_with("--") => return Err(format!("Unknown option {flag}")),
+                _ => terms.push(flag.to_owned()),
+            }
+            Ok(())
+        })?;
+
+        options.selection = Selection::parse(&terms)?;
+        options.machine = machine.unwrap_or_else(history::machine);
+        Ok(options)
+    }
+}
+
+/// Downloads any missing inputs for the selected days.
+fn fetch(args: impl Iterator<Item = String>) {
+    let options = FetchOptions::parse(args).unwrap_or_else(|message| usage(&message));
+    let dir = options.input_dir.clone().unwrap_or_else(input_dir);
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+
+    // Puzzles that haven't unlocked yet would only get an error from the server.
+    let days: Vec<_> = calendar(unlocked(now))
+        .filter(|&(year, day)| options.selection.matches(year, day))
+        .collect();
+    if days.is_empty() {
+        usage("No unlocked days match the selection");
+    }
+
+    // Only look for a session token once there is something to download.
+    let mut client = None;
+    let mut failed = 0;
+
+    for (year, day) in days {
+        let path = input_path(&dir, year, day);
+        if path.exists() {
+            continue;
+        }
+
+        let client = client.get_or_insert_with(|| match session() {
+            Ok(session) => Client::new(&options.base_url, &session, options.delay),
+            Err(message) => usage(&message),
+        });
+
+        match site::fetch::fetch(client, year, day, &path) {
+            Ok(_) => {
+                println!("{BOLD}{GREEN}{year} Day {day:02}{RESET} saved to {}", path.display())
+            }
+            Err(message) => {
+                eprintln!("{BOLD}{RED}{year} Day {day:02}{RESET} {message}");
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        exit(1);
+    }
+}
+
+struct FetchOptions {
+    selection: Selection,
+    input_dir: Option<PathBuf>,
+    base_url: String,
+    delay: Duration,
+}
+
+impl FetchOptions {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = FetchOptions {
+            selection: Selection::default(),
+            input_dir: None,
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            delay: DEFAULT_DELAY,
+        };
+        let mut terms = Vec::new();
+
+        parse_args(args, |flag, value| {
+            match flag {
+                "--input-dir" => options.input_dir = Some(value()?.into()),
+                "--base-url" => options.base_url = value()?,
+                "--delay" => options.delay = seconds(flag, &value()?)?.unwrap_or_default(),
+                _ if flag.starts_with("--") => return Err(format!("Unknown option {flag}")),
+                _ => terms.push(flag.to_owned()),
+            }
+            Ok(())
+        })?;
+
+        options.selection = Selection::parse(&terms)?;
+        Ok(options)
+    }
+}
+
+/// Solves a single part of a single day, then submits the answer.
+fn submit(args: impl Iterator<Item = String>) {
+    let options = SubmitOptions::parse(args).unwrap_or_else(|message| usage(&message));
+    let matches: Vec<_> = solutions()
+        .iter()
+        .filter(|solution| options.selection.matches(solution.year, solution.day))
+        .collect();
+    let [solution] = matches[..] else {
+        usage(&format!("submit needs exactly one day, found {} days", matches.len()));
+    };
+    let Entry { year, day, .. } = *solution;
+    let part = options.part;
+    let dir = options.input_dir.clone().unwrap_or_else(input_dir);
+
+    // Use the answer from the command line, otherwise solve the puzzle.
+    let answer = options.answer.clone().unwrap_or_else(|| {
+        let record = match &options.input {
+            Some(input) => {
+                let (path, data) = read_input(input).unwrap_or_else(|message| usage(&message));
+                isolate::solve(solution, path, data, Sampling::default(), options.timeout)
+            }
+            None => {
+                isolate::run(solution, solution.path_in(&dir), Sampling::default(), options.timeout)
+            }
+        };
+
+        let answer = if part == 1 { &record.part1 } else { &record.part2 };
+        match (record.status, answer) {
+            (Status::Ok, Some(answer @ (Answer::Integer(_) | Answer::Text(_)))) => {
+                answer.to_string()
+            }
+            (Status::Ok, Some(Answer::Art(_))) => {
+                usage("Read the letters from the ASCII art then use --answer to submit them")
+            }
+            (Status::Ok, _) => usage(&format!("{year} Day {day:02} has no answer for part {part}")),
+            (Status::MissingInput, _) => usage(&format!("Missing input {}", record.path.display())),
+            _ => {
+                usage(&format!("{year} Day {day:02} failed: {}", record.error.unwrap_or_default()))
             }
+        }
+    });
+
+    let session = session().unwrap_or_else(|message| usage(&message));
+    let mut client = Client::new(&options.base_url, &session, DEFAULT_DELAY);
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    let store = answers::path(&dir, solution);
+
+    println!("{BOLD}{WHITE}{year} Day {day:02}{RESET} Submitting {answer} for part {part}");
+    match site::submit::submit(&mut client, (year, day, part), &answer, &store, now) {
+        Ok(reply @ Reply::Correct) => println!("{BOLD}{GREEN}{reply}{RESET}"),
+        Ok(reply) => {
+            eprintln!("{BOLD}{RED}{reply}{RESET}");
+            exit(1);
+        }
+        Err(message) => {
+            eprintln!("{BOLD}{RED}{message}{RESET}");
+            exit(1);
+        }
+    }
+}
+
+struct SubmitOptions {
+    selection: Selection,
+    part: usize,
+    answer: Option<String>,
+    input_dir: Option<PathBuf>,
+    input: Option<String>,
+    timeout: Option<Duration>,
+    base_url: String,
+}
+
+impl SubmitOptions {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = SubmitOptions {
+            selection: Selection::default(),
+            part: 0,
+            answer: None,
+            input_dir: None,
+            input: None,
+            timeout: Some(DEFAULT_TIMEOUT),
+            base_url: DEFAULT_BASE_URL.to_owned(),
+        };
+        let mut positional = Vec::new();
 
-            // Display part2_time
-            if part2_time > 1000 {
-                println!("    Part 2: {part2} ({:.1} ms)", part2_time as f32 / 1000f32);
-            } else {
-                println!("    Part 2: {part2} ({} µs)", part2_time);
+        parse_args(args, |flag, value| {
+            match flag {
+                "--answer" => options.answer = Some(value()?.trim().to_owned()),
+                "--input-dir" => options.input_dir = Some(value()?.into()),
+                "--input" => options.input = Some(value()?),
+                "--timeout" => options.timeout = seconds(flag, &value()?)?,
+                "--base-url" => options.base_url = value()?,
+                _ if flag.starts_with("--") => return Err(format!("Unknown option {flag}")),
+                _ => positional.push(flag.to_owned()),
             }
+            Ok(())
+        })?;
+
+        let [selection, part] = &positional[..] else {
+            return Err("Usage: submit yearYYYY::dayDD <1|2> [--answer <answer>]".to_owned());
+        };
+        options.selection = Selection::parse(&[selection])?;
+        options.part = match part.as_str() {
+            "1" => 1,
+            "2" => 2,
+            _ => return Err(format!("Part must be 1 or 2, found \"{part}\"")),
+        };
+        Ok(options)
+    }
+}
+
+/// Creates and registers the files for a new day in the current directory.
+fn new(args: impl Iterator<Item = String>) {
+    let args: Vec<_> = args.collect();
+    let [arg] = &args[..] else {
+        usage("Usage: new yearYYYY::dayDD");
+    };
+
+    let (year, day) = scaffold::parse_day(arg).unwrap_or_else(|message| usage(&message));
+    match scaffold::scaffold(Path::new("."), year, day) {
+        Ok(changes) if changes.is_empty() => {
+            println!("{BOLD}{WHITE}{year} Day {day:02}{RESET} already exists, nothing to do");
+        }
+        Ok(changes) => {
+            println!("{BOLD}{GREEN}{year} Day {day:02}{RESET}");
+            changes.iter().for_each(|change| println!("    {change}"));
+        }
+        Err(message) => {
+            eprintln!("{BOLD}{RED}{message}{RESET}");
+            exit(1);
+        }
+    }
+}
+
+/// Compares two revisions from the timing history, failing if any day got too much slower.
+fn compare(args: impl Iterator<Item = String>) {
+    let options = CompareOptions::parse(args).unwrap_or_else(|message| usage(&message));
+    let timings = history::load(&options.history).unwrap_or_else(|message| usage(&message));
+    let machine = &options.machine;
+    let revisions = history::revisions(&timings, machine);
+
+    // Unless given, compare the latest revision with the one recorded before it.
+    let missing = || usage(&format!("Not enough revisions in the history for {machine}"));
+    let current = options.current.or_else(|| revisions.last().cloned()).unwrap_or_else(missing);
+    let baseline = options
+        .baseline
+        .or_else(|| {
+            let index = revisions.iter().position(|revision| *revision == current)?;
+            index.checked_sub(1).map(|index| revisions[index].clone())
+        })
+        .unwrap_or_else(missing);
+
+    let changes = history::compare(&timings, machine, &baseline, &current);
+    if changes.is_empty() {
+        usage(&format!("No days in common between {baseline} and {current} on {machine}"));
+    }
+
+    println!("{BOLD}{WHITE}{baseline} → {current} on {machine}{RESET}");
+    let mut slower = 0;
+
+    for change in &changes {
+        let history::Change { year, day, baseline, current } = *change;
+        let percent = change.percent();
+        let times = format!("{} → {}", format::duration(baseline), format::duration(current));
+
+        if percent > options.threshold {
+            println!("{BOLD}{RED}{year} Day {day:02}{RESET} {times} {RED}{percent:+.1}%{RESET}");
+            slower += 1;
         } else {
-            eprintln!("{BOLD}{RED}{year} Day {day:02}{RESET}");
-            eprintln!("    Missing input!");
-            eprintln!("    Place input file in {BOLD}{WHITE}{}{RESET}", path.display());
+            println!("{BOLD}{GREEN}{year} Day {day:02}{RESET} {times} {percent:+.1}%");
         }
     }
 
-    // Optionally print totals.
-    if args().any(|a| a == "--totals") {
-        println!("{BOLD}{YELLOW}⭐ {}{RESET}", 2 * solutions.len());
-        println!("{BOLD}{WHITE}🕓 {} ms{RESET}", duration.as_millis());
-    }
-}
-
-struct Solution {
-    year: u32,
-    day: u32,
-    path: PathBuf,
-    wrapper: fn(String) -> (String, String, u128, u128),
-}
-
-macro_rules! run {
-    ($year:tt $($day:tt),*) => {
-        fn $year() -> Vec<Solution> {
-            vec![$({
-                let year = stringify!($year);
-                let day = stringify!($day);
-                let path = Path::new("input").join(year).join(day).with_extension("txt");
-
-                let wrapper = |data: String| {
-                    use $year::$day::*;
-
-                    // print year and day
-                    // let input = parse(&data);
-
-                    // get duration
-                    let instant = Instant::now();
-                    let part1 = part1(&data);
-                    let part1_time = instant.elapsed().as_micros();
-                    let instant = Instant::now();
-                    let part2 = part2(&data);
-                    let part2_time = instant.elapsed().as_micros();
-
-
-
-                    (part1.to_string(), part2.to_string(), part1_time, part2_time)
-                };
-
-                Solution { year: year.unsigned(), day: day.unsigned(), path, wrapper }
-            },)*]
-        }
-    }
-}
-
-// run!(year2015
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-// run!(year2016
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-// run!(year2017
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-// run!(year2018
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-run!(year2019
-    day01, day02, day03, day04, day05, day06
-    // , day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    // day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-);
-//
-// run!(year2020
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-// run!(year2021
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-// run!(year2022
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
-//
-run!(year2023
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24
-);
-//
-run!(year2024
-    day01,
-    day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22,day23,  day24, day25
-);
-
-run!(year2025
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12
-);
+    if slower > 0 {
+        eprintln!("{BOLD}{RED}{slower} day(s) more than {}% slower{RESET}", options.threshold);
+        exit(1);
+    }
+}
+
+struct CompareOptions {
+    history: PathBuf,
+    machine: String,
+    baseline: Option<String>,
+    current: Option<String>,
+    threshold: f64,
+}
+
+impl CompareOptions {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut history = None;
+        let mut machine = None;
+        let mut baseline = None;
+        let mut current = None;
+        let mut threshold = DEFAULT_THRESHOLD;
+
+        parse_args(args, |flag, value| {
+            match flag {
+                "--history" => history = Some(value()?.into()),
+                "--machine" => machine = Some(history::sanitize(&value()?)),
+                "--baseline" => baseline = Some(value()?),
+                "--current" => current = Some(value()?),
+                "--threshold" => {
+                    let value = value()?;
+                    threshold = value
+                        .parse()
+                        .ok()
+                        .filter(|threshold: &f64| *threshold >= 0.0)
+                        .ok_or(format!("{flag} expects a percentage, found \"{value}\""))?;
+                }
+                _ => return Err(format!("Unknown option {flag}")),
+            }
+            Ok(())
+        })?;
+
+        Ok(CompareOptions {
+            history: history.unwrap_or_else(|| history::DEFAULT_PATH.into()),
+            machine: machine.unwrap_or_else(history::machine),
+            baseline,
+            current,
+            threshold,
+        })
+    }
+}
+
+/// Runs the selected days then writes the results as Markdown and HTML tables.
+fn report(args: impl Iterator<Item = String>) {
+    let options = ReportOptions::parse(args).unwrap_or_else(|message| usage(&message));
+    let dir = options.input_dir.clone().unwrap_or_else(input_dir);
+    // Titles missing from the table are read from the sources in the repository holding the
+    // inputs, the same place that stored answers are found.
+    let root = dir.parent().unwrap_or(&dir);
+    let solutions: Vec<_> = solutions()
+        .iter()
+        .filter(|solution| options.selection.matches(solution.year, solution.day))
+        .collect();
+
+    if solutions.is_empty() {
+        usage("No solutions match the selection");
+    }
+
+    let run = |solution: &&'static Entry| {
+        isolate::run(solution, solution.path_in(&dir), options.sampling, options.timeout)
+    };
+    let mut rows = Vec::new();
+
+    let result = jobs::run(&solutions, options.jobs, run, |solution, mut record| {
+        record.verify(&Expected::load(&dir, solution));
+        let title = report::title(root, record.year, record.day);
+        rows.push(Row { record, title });
+    });
+    if let Err(message) = result {
+        usage(&format!("Unable to start jobs: {message}"));
+    }
+
+    let outputs = [
+        (&options.markdown, report::markdown(&rows, options.slowest)),
+        (&options.html, report::html(&rows, options.slowest)),
+    ];
+    for (path, contents) in outputs {
+        let written = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .map_or(Ok(()), create_dir_all)
+            .and_then(|()| write(path, contents));
+        match written {
+            Ok(()) => println!("{BOLD}{GREEN}Saved{RESET} {}", path.display()),
+            Err(error) => {
+                eprintln!("{BOLD}{RED}Unable to save {}: {error}{RESET}", path.display());
+                exit(1);
+            }
+        }
+    }
+}
+
+struct ReportOptions {
+    selection: Selection,
+    input_dir: Option<PathBuf>,
+    markdown: PathBuf,
+    html: PathBuf,
+    slowest: usize,
+    sampling: Sampling,
+    timeout: Option<Duration>,
+    jobs: usize,
+}
+
+impl ReportOptions {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = ReportOptions {
+            selection: Selection::default(),
+            input_dir: None,
+            markdown: "docs/results.md".into(),
+            html: "docs/results.html".into(),
+            slowest: report::DEFAULT_SLOWEST,
+            sampling: Sampling::default(),
+            timeout: Some(DEFAULT_TIMEOUT),
+            jobs: 1,
+        };
+        let mut terms = Vec::new();
+
+        parse_args(args, |flag, value| {
+            match flag {
+                "--input-dir" => options.input_dir = Some(value()?.into()),
+                "--markdown" => options.markdown = value()?.into(),
+                "--html" => options.html = value()?.into(),
+                "--slowest" => options.slowest = number(flag, &value()?)?,
+                "--iterations" => match number(flag, &value()?)? {
+                    0 => return Err("--iterations must be at least 1".to_owned()),
+                    n => options.sampling.iterations = n,
+                },
+                "--timeout" => options.timeout = seconds(flag, &value()?)?,
+                "--jobs" => options.jobs = number(flag, &value()?)?,
+                _ if flag.starts_with("--") => return Err(format!("Unknown option {flag}")),
+                _ => terms.push(flag.to_owned()),
+            }
+            Ok(())
+        })?;
+
+        options.selection = Selection::parse(&terms)?;
+        Ok(options)
+    }
+}
+
+/// Answers requests to solve puzzles over HTTP until stopped.
+fn serve(args: impl Iterator<Item = String>) {
+    let options = ServeOptions::parse(args).unwrap_or_else(|message| usage(&message));
+    let address = format!("{}:{}", options.host, options.port);
+    let listener = TcpListener::bind(&address)
+        .unwrap_or_else(|error| usage(&format!("Unable to listen on {address}: {error}")));
+
+    let address = listener.local_addr().map_or(address, |address| address.to_string());
+    println!("{BOLD}{GREEN}Serving {} solutions{RESET} on http://{address}", solutions().len());
+
+    let message = serve::serve(listener, options.jobs, Sampling::default(), options.timeout);
+    eprintln!("{BOLD}{RED}{message}{RESET}");
+    exit(1);
+}
+
+struct ServeOptions {
+    host: String,
+    port: u16,
+    jobs: usize,
+    timeout: Option<Duration>,
+}
+
+impl ServeOptions {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = ServeOptions {
+            host: "127.0.0.1".to_owned(),
+            port: 8080,
+            jobs: 4,
+            timeout: Some(DEFAULT_TIMEOUT),
+        };
+
+        parse_args(args, |flag, value| {
+            match flag {
+                "--host" => options.host = value()?,
+                "--port" => {
+                    let value = value()?;
+                    options.port = value
+                        .parse()
+                        .map_err(|_| format!("{flag} expects a port number, found \"{value}\""))?;
+                }
+                "--jobs" => options.jobs = number(flag, &value()?)?,
+                "--timeout" => options.timeout = seconds(flag, &value()?)?,
+                _ => return Err(format!("Unknown option {flag}")),
+            }
+            Ok(())
+        })?;
+
+        Ok(options)
+    }
+}
+
+/// Calls `handle` with each flag, or the whole argument if it isn't a flag, along with a function
+/// that returns the value of the flag given as either `--flag=value` or `--flag value`.
+fn parse_args(
+    mut args: impl Iterator<Item = String>,
+    mut handle: impl FnMut(&str, &mut dyn FnMut() -> Result<String, String>) -> Result<(), String>,
+) -> Result<(), String> {
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if arg.starts_with("--") => (flag, Some(value.to_owned())),
+            _ => (arg.as_str(), None),
+        };
+        let mut value =
+            || inline.clone().or_else(|| args.next()).ok_or(format!("{flag} needs a value"));
+        handle(flag, &mut value)?;
+    }
+    Ok(())
+}
+
+/// Reports a problem with the command line then exits.
+fn usage(message: &str) -> ! {
+    eprintln!("{BOLD}{RED}{message}{RESET}");
+    exit(2);
+}
+
+/// Points the process's `stdout` at `stderr`, returning a handle to the original `stdout`.
+///
+/// Many solutions print debugging output with `println!`, which would otherwise be mixed in with
+/// JSON or CSV records. This can't be undone, for the rest of the process anything written to
+/// `stdout` goes to `stderr` instead. Only Unix is supported, elsewhere or if redirecting fails
+/// `stdout` is returned unchanged.
+#[cfg(unix)]
+fn take_stdout() -> Box<dyn Write> {
+    use std::fs::File;
+    use std::os::fd::{AsFd, AsRawFd};
+
+    extern "C" {
+        fn dup2(src: i32, dst: i32) -> i32;
+    }
+
+    let stdout = io::stdout();
+    let mut lock = stdout.lock();
+    let _ = lock.flush();
+
+    let Ok(original) = lock.as_fd().try_clone_to_owned() else {
+        return Box::new(io::stdout());
+    };
+    // Safety: both file descriptors are open, and `dup2` only replaces the one for `stdout`,
+    // which the standard library keeps using as is.
+    if unsafe { dup2(io::stderr().as_raw_fd(), lock.as_raw_fd()) } < 0 {
+        return Box::new(io::stdout());
+    }
+    Box::new(File::from(original))
+}
+
+#[cfg(not(unix))]
+fn take_stdout() -> Box<dyn Write> {
+    Box::new(io::stdout())
+}
+
+/// Reads a single input from a file, or from stdin when `input` is `-`.
+fn read_input(input: &str) -> Result<(PathBuf, String), String> {
+    let data = if input == "-" { read_all(stdin()) } else { read_to_string(input) };
+    data.map(|data| (PathBuf::from(input), data))
+        .map_err(|error| format!("Unable to read input {input}: {error}"))
+}
+
+fn number(flag: &str, value: &str) -> Result<usize, String> {
+    value.parse().map_err(|_| format!("{flag} expects a number, found \"{value}\""))
+}
+
+/// Parses a number of seconds, where zero means no limit.
+fn seconds(flag: &str, value: &str) -> Result<Option<Duration>, String> {
+    let error = || format!("{flag} expects a number of seconds, found \"{value}\"");
+    let seconds: f64 = value.parse().map_err(|_| error())?;
+    let timeout = Duration::try_from_secs_f64(seconds).map_err(|_| error())?;
+    Ok(Some(timeout).filter(|timeout| !timeout.is_zero()))
+}
@@ -0,0 +1,200 @@
+//! Expected answers stored alongside the inputs, used to catch regressions.
+//!
+//! Answers for each day live in `answers/yearYYYY/dayDD.txt`, mirroring the `input` directory.
+//! The `answers` directory always sits next to the input directory, so inputs moved elsewhere
+//! with `--input-dir` or `AOC_INPUT_DIR` keep their answers alongside them.
+//! Each line is a `key: value` pair, for example:
+//!
+//! ```none
+//! part1: 1320
+//! part2: co,de,ka,ta
+//! ```
+//!
+//! Either part may be left out if the answer is not known yet. Lines with any other key are
+//! ignored so that the file can hold extra notes.
+//!
+//! The same file also keeps a history of [`Submissions`] so that an answer that was already
+//! rejected is never sent twice:
+//!
+//! ```none
+//! part1_too_low: 1000
+//! part1_wrong: 1500
+//! wait_until: 1733029200
+//! ```
+//!
+//! New lines are always appended, with later lines taking precedence over earlier ones.
+use crate::solution::{Answer, Entry};
+use std::fs::{create_dir_all, read_to_string, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Known correct answers for a single day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    /// Loads the answers for `entry` kept beside the inputs in `input_dir`, returning empty
+    /// answers if the file does not exist.
+    pub fn load(input_dir: &Path, entry: &Entry) -> Self {
+        Expected::parse(&read(&path(input_dir, entry)))
+    }
+
+    pub fn parse(text: &str) -> Self {
+        let mut expected = Expected::default();
+
+        for line in text.lines() {
+            let Some((key, value)) = line.split_once(':') else { continue };
+            let value = Some(value.trim().to_owned()).filter(|value| !value.is_empty());
+
+            match key.trim() {
+                "part1" => expected.part1 = value,
+                "part2" => expected.part2 = value,
+                _ => (),
+            }
+        }
+
+        expected
+    }
+}
+
+/// Result of comparing a computed answer against the stored answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// Holds the expected answer.
+    Wrong(String),
+    /// No stored answer, or an answer such as ASCII art that can only be checked by eye.
+    Unknown,
+}
+
+impl Verdict {
+    pub fn new(actual: Option<&Answer>, expected: Option<&str>) -> Self {
+        let Some(expected) = expected else { return Verdict::Unknown };
+
+        match actual {
+            Some(Answer::Art(_)) => Verdict::Unknown,
+            Some(answer) if answer.to_string().trim() == expected => Verdict::Correct,
+            _ => Verdict::Wrong(expected.to_owned()),
+        }
+    }
+
+    /// Stable identifier used by machine readable formats.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong(_) => "wrong",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+/// Answers previously rejected by the website, used to avoid submitting them again.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Submissions {
+    /// Every rejected answer for each part, including those that were too high or too low.
+    pub wrong: [Vec<String>; 2],
+    /// Smallest answer for each part known to be too high.
+    pub too_high: [Option<i128>; 2],
+    /// Largest answer for each part known to be too low.
+    pub too_low: [Option<i128>; 2],
+    /// No answers can be submitted before this time, in seconds since the Unix epoch.
+    pub wait_until: Option<u64>,
+}
+
+impl Submissions {
+    pub fn parse(text: &str) -> Self {
+        let mut submissions = Submissions::default();
+
+        for line in text.lines() {
+            let Some((key, value)) = line.split_once(':') else { continue };
+            let (key, value) = (key.trim(), value.trim());
+
+            if key == "wait_until" {
+                submissions.wait_until = value.parse().ok();
+                continue;
+            }
+
+            let Some((part, kind)) = key.split_once('_') else { continue };
+            let index = match part {
+                "part1" => 0,
+                "part2" => 1,
+                _ => continue,
+            };
+            let number = value.parse::<i128>().ok();
+
+            match kind {
+                "too_high" => {
+                    let high = &mut submissions.too_high[index];
+                    *high = number.map(|n| high.map_or(n, |high| high.min(n))).or(*high);
+                }
+                "too_low" => {
+                    let low = &mut submissions.too_low[index];
+                    *low = number.map(|n| low.map_or(n, |low| low.max(n))).or(*low);
+                }
+                "wrong" => (),
+                _ => continue,
+            }
+            submissions.wrong[index].push(value.to_owned());
+        }
+
+        submissions
+    }
+
+    /// Explains why `answer` for `part` shouldn't be submitted at time `now`, if at all.
+    pub fn check(&self, part: usize, answer: &str, now: u64) -> Result<(), String> {
+        let index = part - 1;
+
+        if let Some(wait) = self.wait_until.filter(|&until| until > now).map(|until| until - now) {
+            return Err(format!("Too soon to submit again, wait another {wait}s"));
+        }
+        if self.wrong[index].iter().any(|wrong| wrong == answer) {
+            return Err(format!("{answer} was already rejected for part {part}"));
+        }
+        if let Ok(n) = answer.parse::<i128>() {
+            if let Some(high) = self.too_high[index].filter(|&high| n >= high) {
+                return Err(format!("{answer} must be too high, as {high} already was"));
+            }
+            if let Some(low) = self.too_low[index].filter(|&low| n <= low) {
+                return Err(format!("{answer} must be too low, as {low} already was"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the answers file at `path`, returning an empty string if it doesn't exist.
+pub fn read(path: &Path) -> String {
+    read_to_string(path).unwrap_or_default()
+}
+
+/// Appends a single `key: value` line to the answers file at `path`, creating it if needed.
+pub fn append(path: &Path, key: &str, value: &str) -> Result<(), String> {
+    let error = |e: std::io::Error| format!("Unable to update {}: {e}", path.display());
+
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent).map_err(error)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path).map_err(error)?;
+    // Start on a new line even if the file was edited by hand without a trailing newline.
+    let text = read(path);
+    let separator = if text.is_empty() || text.ends_with('\n') { "" } else { "\n" };
+    writeln!(file, "{separator}{key}: {value}").map_err(error)
+}
+
+/// Directory of stored answers for the inputs in `input_dir`, its sibling named `answers`.
+/// For example `../private/input` keeps its answers in `../private/answers`.
+pub fn dir(input_dir: &Path) -> PathBuf {
+    input_dir.parent().unwrap_or(input_dir).join("answers")
+}
+
+/// Location of the stored answers for `entry`, for example `answers/year2023/day07.txt` when
+/// the inputs are in `input`.
+pub fn path(input_dir: &Path, entry: &Entry) -> PathBuf {
+    let Entry { year, day, .. } = entry;
+    dir(input_dir).join(format!("year{year}")).join(format!("day{day:02}.txt"))
+}
@@ -0,0 +1,431 @@
+//! Renders [`Record`]s as colored text for humans or as JSON and CSV for other tools.
+//!
+//! Machine readable output goes to `stdout` with one entry per selected day, in the same order
+//! as the text output. Missing inputs, panics and timeouts are reported through the `status`
+//! field instead of on `stderr`, so the output can be piped without losing any days. The `aoc`
+//! binary moves anything the solutions themselves print, such as debugging output, to `stderr`
+//! so that it can't end up in the middle of the records. The status
+//! is one of `ok`, `missing_input`, `invalid_input`, `panicked` or `timeout`, with the reason
+//! the input was rejected, the panic message or the timeout in the `error` field. Any changes
+//! made to normalize the input are listed in `input_notes`, separated by `; `.
+//!
+//! JSON is a single array of objects, written incrementally as each day finishes:
+//!
+//! ```none
+//! [
+//! {"year":2024,"day":23,"status":"ok","part1":7,"part2":"co,de,ka,ta","iterations":1,...}
+//! ]
+//! ```
+//!
+//! Integer answers are JSON numbers, text and ASCII art answers are strings and parts without
+//! an answer are `null`. CSV uses the same fields in the same order with a header row, quoting
+//! fields as needed. The full list of fields is [`FIELDS`].
+//!
+//! Timings are in nanoseconds. The plain `parse_ns`, `part1_ns` and `part2_ns` fields are the
+//! median over all iterations, followed by the minimum, mean and 95th percentile. Phases that
+//! never ran, such as every phase of a missing input, have `null` timings and `iterations`
+//! rather than `0`.
+//!
+//! The `part1_verdict` and `part2_verdict` fields are one of `correct`, `wrong` or `unknown`
+//! when answers are verified and `null` otherwise. The `_expected` fields hold the stored answer
+//! when it differs from the actual answer.
+//!
+//! When built with the `memory` feature each phase also reports the number of allocations, the
+//! total bytes allocated and the peak live bytes, which are otherwise `null`. See
+//! [`memory`](super::memory) for details.
+use super::answers::Verdict;
+use super::memory::Usage;
+use super::record::{Record, Status};
+use super::stats::Stats;
+use crate::solution::Answer;
+use crate::util::ansi::*;
+use std::fmt::Write as _;
+use std::io::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Unknown format \"{s}\", expected one of json, csv or text")),
+        }
+    }
+}
+
+/// Names of the machine readable fields, in output order.
+pub const FIELDS: [&str; 35] = [
+    "year",
+    "day",
+    "status",
+    "part1",
+    "part2",
+    "iterations",
+    "parse_ns",
+    "parse_min_ns",
+    "parse_mean_ns",
+    "parse_p95_ns",
+    "part1_ns",
+    "part1_min_ns",
+    "part1_mean_ns",
+    "part1_p95_ns",
+    "part2_ns",
+    "part2_min_ns",
+    "part2_mean_ns",
+    "part2_p95_ns",
+    "input",
+    "input_md5",
+    "part1_verdict",
+    "part2_verdict",
+    "part1_expected",
+    "part2_expected",
+    "error",
+    "input_notes",
+    "parse_allocations",
+    "parse_allocated_bytes",
+    "parse_peak_bytes",
+    "part1_allocations",
+    "part1_allocated_bytes",
+    "part1_peak_bytes",
+    "part2_allocations",
+    "part2_allocated_bytes",
+    "part2_peak_bytes",
+];
+
+/// A single machine readable value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    Number(String),
+    Text(String),
+    Null,
+}
+
+impl Value {
+    fn number(n: impl ToString) -> Self {
+        Value::Number(n.to_string())
+    }
+
+    fn text(s: Option<&str>) -> Self {
+        s.map_or(Value::Null, |s| Value::Text(s.to_owned()))
+    }
+
+    fn answer(answer: Option<&Answer>) -> Self {
+        match answer {
+            Some(Answer::Integer(n)) => Value::number(n),
+            Some(Answer::Text(text) | Answer::Art(text)) => Value::Text(text.clone()),
+            Some(Answer::Empty) | None => Value::Null,
+        }
+    }
+
+    fn verdict(verdict: Option<&Verdict>) -> Self {
+        Value::text(verdict.map(Verdict::as_str))
+    }
+
+    fn expected(verdict: Option<&Verdict>) -> Self {
+        match verdict {
+            Some(Verdict::Wrong(expected)) => Value::text(Some(expected)),
+            _ => Value::Null,
+        }
+    }
+
+    fn iterations(stats: &Stats) -> Self {
+        if stats.samples == 0 {
+            Value::Null
+        } else {
+            Value::number(stats.samples)
+        }
+    }
+
+    fn stats(stats: &Stats) -> [Self; 4] {
+        let times = [stats.median, stats.min, stats.mean, stats.p95];
+        times.map(|d| if stats.samples == 0 { Value::Null } else { Value::number(d.as_nanos()) })
+    }
+
+    fn memory(usage: Option<&Usage>) -> [Self; 3] {
+        match usage {
+            Some(usage) => [usage.allocations, usage.bytes, usage.peak].map(Value::number),
+            None => [Value::Null, Value::Null, Value::Null],
+        }
+    }
+}
+
+/// Values for each of the [`FIELDS`] of a record.
+pub fn values(record: &Record) -> [Value; FIELDS.len()] {
+    let [parse_ns, parse_min_ns, parse_mean_ns, parse_p95_ns] = Value::stats(&record.parse_time);
+    let [part1_ns, part1_min_ns, part1_mean_ns, part1_p95_ns] = Value::stats(&record.part1_time);
+    let [part2_ns, part2_min_ns, part2_mean_ns, part2_p95_ns] = Value::stats(&record.part2_time);
+    let [parse_allocations, parse_bytes, parse_peak] = Value::memory(record.parse_memory.as_ref());
+    let [part1_allocations, part1_bytes, part1_peak] = Value::memory(record.part1_memory.as_ref());
+    let [part2_allocations, part2_bytes, part2_peak] = Value::memory(record.part2_memory.as_ref());
+
+    [
+        Value::number(record.year),
+        Value::number(record.day),
+        Value::text(Some(record.status.as_str())),
+        Value::answer(record.part1.as_ref()),
+        Value::answer(record.part2.as_ref()),
+        Value::iterations(&record.parse_time),
+        parse_ns,
+        parse_min_ns,
+        parse_mean_ns,
+        parse_p95_ns,
+        part1_ns,
+        part1_min_ns,
+        part1_mean_ns,
+        part1_p95_ns,
+        part2_ns,
+        part2_min_ns,
+        part2_mean_ns,
+        part2_p95_ns,
+        Value::text(Some(&record.path.display().to_string())),
+        Value::text(record.hash.as_deref()),
+        Value::verdict(record.part1_verdict.as_ref()),
+        Value::verdict(record.part2_verdict.as_ref()),
+        Value::expected(record.part1_verdict.as_ref()),
+        Value::expected(record.part2_verdict.as_ref()),
+        Value::text(record.error.as_deref()),
+        Value::text(Some(record.notes.join("; ").as_str()).filter(|notes| !notes.is_empty())),
+        parse_allocations,
+        parse_bytes,
+        parse_peak,
+        part1_allocations,
+        part1_bytes,
+        part1_peak,
+        part2_allocations,
+        part2_bytes,
+        part2_peak,
+    ]
+}
+
+/// Writes records one at a time, keeping track of any separators needed between them.
+pub struct Printer {
+    format: Format,
+    count: usize,
+    out: Box<dyn Write>,
+}
+
+impl Printer {
+    /// Writes JSON and CSV records to `out`, while text is always printed to the terminal.
+    pub fn new(format: Format, out: Box<dyn Write>) -> Self {
+        Printer { format, count: 0, out }
+    }
+
+    pub fn begin(&mut self) {
+        match self.format {
+            Format::Text => (),
+            Format::Json => self.line("["),
+            Format::Csv => self.line(&FIELDS.join(",")),
+        }
+    }
+
+    pub fn record(&mut self, record: &Record) {
+        match self.format {
+            Format::Text => text(record),
+            Format::Json => {
+                let separator = if self.count > 0 { "," } else { "" };
+                self.line(&format!("{separator}{}", json(record)));
+            }
+            Format::Csv => self.line(&csv(record)),
+        }
+        self.count += 1;
+    }
+
+    pub fn end(&mut self) {
+        if self.format == Format::Json {
+            self.line("]");
+        }
+    }
+
+    /// Writes and flushes a line straight away so that other tools can follow along.
+    fn line(&mut self, line: &str) {
+        let _ = writeln!(self.out, "{line}").and_then(|()| self.out.flush());
+    }
+}
+
+fn text(record: &Record) {
+    let Record { year, day, .. } = record;
+
+    match record.status {
+        Status::Ok => {
+            println!("{BOLD}{GREEN}{year} Day {day:02}{RESET}");
+            record.notes.iter().for_each(|note| println!("    {YELLOW}Note: {note}{RESET}"));
+            let parse = text_time(&record.parse_time, record.parse_memory.as_ref());
+            let part1 = text_time(&record.part1_time, record.part1_memory.as_ref());
+            let part2 = text_time(&record.part2_time, record.part2_memory.as_ref());
+            println!("    Parse: ({parse})");
+            text_part(1, record.part1.as_ref(), part1, record.part1_verdict.as_ref());
+            text_part(2, record.part2.as_ref(), part2, record.part2_verdict.as_ref());
+        }
+        Status::MissingInput => {
+            eprintln!("{BOLD}{RED}{year} Day {day:02}{RESET}");
+            eprintln!("    Missing input!");
+            eprintln!("    Place input file in {BOLD}{WHITE}{}{RESET}", record.path.display());
+        }
+        Status::InvalidInput => {
+            eprintln!("{BOLD}{RED}{year} Day {day:02} INVALID INPUT{RESET}");
+            record.error.iter().flat_map(|error| error.lines()).for_each(|line| {
+                eprintln!("    {line}");
+            });
+            eprintln!("    Check input file {BOLD}{WHITE}{}{RESET}", record.path.display());
+        }
+        Status::Panicked | Status::Timeout => {
+            let label = if record.status == Status::Timeout { "TIMEOUT" } else { "FAILED" };
+            eprintln!("{BOLD}{RED}{year} Day {day:02} {label}{RESET}");
+            if let Some(error) = &record.error {
+                eprintln!("    {error}");
+            }
+            record.notes.iter().for_each(|note| eprintln!("    {YELLOW}Note: {note}{RESET}"));
+            text_wrong(1, record.part1_verdict.as_ref());
+            text_wrong(2, record.part2_verdict.as_ref());
+        }
+    }
+}
+
+fn text_part(part: u32, answer: Option<&Answer>, time: String, verdict: Option<&Verdict>) {
+    let mark = match verdict {
+        Some(Verdict::Correct) => format!(" {GREEN}✔{RESET}"),
+        Some(Verdict::Wrong(expected)) => format!(" {BOLD}{RED}✘ expected {expected}{RESET}"),
+        Some(Verdict::Unknown) => format!(" {YELLOW}?{RESET}"),
+        None => String::new(),
+    };
+
+    match answer {
+        Some(answer @ Answer::Art(_)) => {
+            println!("    Part {part}: ({time}){mark}");
+            answer.lines().for_each(|line| println!("        {line}"));
+        }
+        Some(Answer::Empty) | None => println!("    Part {part}: - ({time}){mark}"),
+        Some(answer) => println!("    Part {part}: {answer} ({time}){mark}"),
+    }
+}
+
+fn text_wrong(part: u32, verdict: Option<&Verdict>) {
+    if let Some(Verdict::Wrong(expected)) = verdict {
+        eprintln!("    Part {part}: {BOLD}{RED}✘ expected {expected}{RESET}");
+    }
+}
+
+/// A single sample is shown as is, otherwise the median followed by the spread. Memory usage
+/// follows when counted.
+fn text_time(stats: &Stats, memory: Option<&Usage>) -> String {
+    let Stats { samples, min, median, mean, p95 } = *stats;
+
+    let time = if samples > 1 {
+        let [min, median, mean, p95] = [min, median, mean, p95].map(duration);
+        format!("median {median}, min {min}, mean {mean}, p95 {p95}, n = {samples}")
+    } else {
+        duration(median)
+    };
+
+    match memory {
+        Some(Usage { allocations, bytes: total, peak }) => {
+            format!(
+                "{time}, {allocations} allocations, {} total, {} peak",
+                bytes(*total),
+                bytes(*peak)
+            )
+        }
+        None => time,
+    }
+}
+
+/// Formats a number of bytes using binary units, to 1 decimal place above 1 KiB.
+pub fn bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+/// Formats a duration in µs, switching to ms rounded to 1 decimal place above 1 ms.
+pub fn duration(duration: Duration) -> String {
+    let micros = duration.as_micros();
+    if micros > 1000 {
+        format!("{:.1} ms", micros as f32 / 1000f32)
+    } else {
+        format!("{micros} µs")
+    }
+}
+
+/// Formats a record as a single line JSON object.
+pub fn json(record: &Record) -> String {
+    let mut out = String::from("{");
+
+    for (i, (name, value)) in FIELDS.iter().zip(values(record)).enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        let _ = write!(out, "\"{name}\":");
+        match value {
+            Value::Number(n) => out.push_str(&n),
+            Value::Text(s) => out.push_str(&json_string(&s)),
+            Value::Null => out.push_str("null"),
+        }
+    }
+
+    out.push('}');
+    out
+}
+
+/// Formats a record as a single CSV row matching the header.
+pub fn csv(record: &Record) -> String {
+    let fields = values(record).map(|value| match value {
+        Value::Number(n) => n,
+        Value::Text(s) => csv_field(&s),
+        Value::Null => String::new(),
+    });
+    fields.join(",")
+}
+
+/// Quotes and escapes a string according to [RFC 8259](https://www.rfc-editor.org/rfc/rfc8259).
+pub fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c < ' ' => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+    out
+}
+
+/// Quotes a field according to [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180) only when
+/// it contains a delimiter, quote or line break.
+pub fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
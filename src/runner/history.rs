@@ -0,0 +1,216 @@
+//! Keeps a history of timings so that performance regressions can be spotted.
+//!
+//! Each run with `--history` appends one CSV row per solved day, recording the git revision and
+//! the machine alongside the median time of each phase in nanoseconds:
+//!
+//! ```none
+//! revision,machine,timestamp,year,day,parse_ns,part1_ns,part2_ns
+//! 1a2b3c4,buildbox,1733097600,2024,1,21870,4375,8125
+//! ```
+//!
+//! The revision is the abbreviated commit hash with `-dirty` appended when there are
+//! uncommitted changes. Timings from different machines can't be compared meaningfully, so
+//! [`compare`] only ever looks at a single machine. The same revision may be run more than once,
+//! in which case the fastest total for each day is used to reduce noise.
+use super::record::{Record, Status};
+use std::collections::BTreeMap;
+use std::fs::{read_to_string, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+/// Used by `compare` when no other history file is given.
+pub const DEFAULT_PATH: &str = "history.csv";
+
+pub const HEADER: &str = "revision,machine,timestamp,year,day,parse_ns,part1_ns,part2_ns";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Timing {
+    pub revision: String,
+    pub machine: String,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub year: u32,
+    pub day: u32,
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timing {
+    /// Median timings of a solved record, or `None` if the solution didn't finish.
+    pub fn new(record: &Record, revision: &str, machine: &str, timestamp: u64) -> Option<Self> {
+        (record.status == Status::Ok).then(|| Timing {
+            revision: revision.to_owned(),
+            machine: machine.to_owned(),
+            timestamp,
+            year: record.year,
+            day: record.day,
+            parse: record.parse_time.median,
+            part1: record.part1_time.median,
+            part2: record.part2_time.median,
+        })
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+
+    fn parse(line: &str) -> Option<Self> {
+        let fields: Vec<_> = line.split(',').map(str::trim).collect();
+        let [revision, machine, timestamp, year, day, parse, part1, part2] = fields[..] else {
+            return None;
+        };
+        let nanos = |s: &str| s.parse().ok().map(Duration::from_nanos);
+
+        Some(Timing {
+            revision: revision.to_owned(),
+            machine: machine.to_owned(),
+            timestamp: timestamp.parse().ok()?,
+            year: year.parse().ok()?,
+            day: day.parse().ok()?,
+            parse: nanos(parse)?,
+            part1: nanos(part1)?,
+            part2: nanos(part2)?,
+        })
+    }
+
+    fn to_csv(&self) -> String {
+        let Timing { revision, machine, timestamp, year, day, .. } = self;
+        let [parse, part1, part2] = [self.parse, self.part1, self.part2].map(|d| d.as_nanos());
+        format!("{revision},{machine},{timestamp},{year},{day},{parse},{part1},{part2}")
+    }
+}
+
+/// A day solved by both revisions being compared.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Change {
+    pub year: u32,
+    pub day: u32,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Change {
+    /// How much slower the current revision is, as a percentage of the baseline. Negative when
+    /// it got faster.
+    pub fn percent(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64();
+        let current = self.current.as_secs_f64();
+        if baseline > 0.0 {
+            100.0 * (current - baseline) / baseline
+        } else if current > 0.0 {
+            f64::INFINITY
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Current git revision, or `unknown` outside a git checkout.
+pub fn revision() -> String {
+    let git = |args: &[&str]| {
+        let output = Command::new("git").args(args).output().ok()?;
+        output.status.success().then(|| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(status) if !status.is_empty() => format!("{hash}-dirty"),
+            _ => hash,
+        },
+        None => "unknown".to_owned(),
+    }
+}
+
+/// Host name of this machine, or `unknown` if it can't be found.
+pub fn machine() -> String {
+    let hostname = || {
+        let output = Command::new("hostname").output().ok()?;
+        output.status.success().then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    read_to_string("/etc/hostname")
+        .ok()
+        .or_else(hostname)
+        .map(|name| sanitize(&name))
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "unknown".to_owned())
+}
+
+/// Makes a machine name safe to store as a single CSV field, trimming it then replacing commas
+/// and line breaks with `_`.
+pub fn sanitize(name: &str) -> String {
+    name.trim().replace([',', '\n', '\r'], "_")
+}
+
+/// Appends timings to the history at `path`, creating it with a header if needed.
+pub fn append(path: &Path, timings: &[Timing]) -> Result<(), String> {
+    let error = |e: std::io::Error| format!("Unable to update {}: {e}", path.display());
+
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(error)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path).map_err(error)?;
+
+    let mut text = String::new();
+    if file.metadata().map_err(error)?.len() == 0 {
+        text = format!("{HEADER}\n");
+    }
+    timings.iter().for_each(|timing| text += &format!("{}\n", timing.to_csv()));
+
+    file.write_all(text.as_bytes()).map_err(error)
+}
+
+/// Reads every timing from the history at `path`.
+pub fn load(path: &Path) -> Result<Vec<Timing>, String> {
+    let text =
+        read_to_string(path).map_err(|e| format!("Unable to read {}: {e}", path.display()))?;
+
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && *line != HEADER)
+        .map(|(i, line)| {
+            Timing::parse(line)
+                .ok_or_else(|| format!("{} line {}: unexpected \"{line}\"", path.display(), i + 1))
+        })
+        .collect()
+}
+
+/// Revisions recorded for `machine`, from oldest to newest by their first run.
+pub fn revisions(timings: &[Timing], machine: &str) -> Vec<String> {
+    let mut revisions: Vec<String> = Vec::new();
+
+    for timing in timings.iter().filter(|timing| timing.machine == machine) {
+        if !revisions.contains(&timing.revision) {
+            revisions.push(timing.revision.clone());
+        }
+    }
+    revisions
+}
+
+/// Compares the days solved by both revisions on `machine`, sorted by year then day.
+pub fn compare(timings: &[Timing], machine: &str, baseline: &str, current: &str) -> Vec<Change> {
+    let fastest = |revision: &str| {
+        let mut days = BTreeMap::new();
+        for timing in timings.iter() {
+            if timing.machine == machine && timing.revision == revision {
+                let total = days.entry((timing.year, timing.day)).or_insert(Duration::MAX);
+                *total = timing.total().min(*total);
+            }
+        }
+        days
+    };
+
+    let baseline = fastest(baseline);
+    let current = fastest(current);
+
+    baseline
+        .into_iter()
+        .filter_map(|((year, day), baseline)| {
+            let current = *current.get(&(year, day))?;
+            Some(Change { year, day, baseline, current })
+        })
+        .collect()
+}
@@ -0,0 +1,52 @@
+//! Checks puzzle inputs before they reach a solution.
+//!
+//! Solutions assume well formed input and tend to panic with an unhelpful message otherwise,
+//! for example when an expired session cookie saved the login page in place of an input. Inputs
+//! that can never be right are rejected with a clear diagnostic. Harmless differences introduced
+//! by editors or other operating systems are normalized, noting each change.
+use std::borrow::Cow;
+
+/// Input ready to pass to a solution, along with a note for each change made.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Checked<'a> {
+    pub data: Cow<'a, str>,
+    pub notes: Vec<String>,
+}
+
+pub fn check(data: &str) -> Result<Checked<'_>, String> {
+    let trimmed = data.trim_start_matches('\u{feff}').trim();
+
+    if trimmed.is_empty() {
+        return Err("Input is empty".to_owned());
+    }
+    if trimmed.contains("Puzzle inputs differ by user") || trimmed.contains("Please log in") {
+        return Err("Input is the website's login message instead of a puzzle input, \
+                    the session has probably expired so download it again"
+            .to_owned());
+    }
+    let start = trimmed.get(..100).unwrap_or(trimmed).to_ascii_lowercase();
+    if start.starts_with("<!doctype") || start.starts_with("<html") || start.contains("<head") {
+        return Err("Input is an HTML page instead of a puzzle input, download it again".to_owned());
+    }
+    if data.contains('\0') {
+        return Err("Input contains NUL bytes, so is probably a binary file".to_owned());
+    }
+
+    let mut data = Cow::Borrowed(data);
+    let mut notes = Vec::new();
+
+    if let Some(rest) = data.strip_prefix('\u{feff}') {
+        data = Cow::Owned(rest.to_owned());
+        notes.push("Removed byte order mark".to_owned());
+    }
+    if data.contains("\r\n") {
+        data = Cow::Owned(data.replace("\r\n", "\n"));
+        notes.push("Converted CRLF line endings to LF".to_owned());
+    }
+    if !data.ends_with('\n') {
+        data.to_mut().push('\n');
+        notes.push("Added missing trailing newline, check the input is complete".to_owned());
+    }
+
+    Ok(Checked { data, notes })
+}
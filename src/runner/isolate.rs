@@ -0,0 +1,159 @@
+//! Runs each solution on its own thread so that one bad day can't stop the rest.
+//!
+//! Panics are already caught by [`record::solve`], however the default panic hook still prints
+//! a message and possibly a backtrace in the middle of the output. Solutions running on a
+//! runner thread instead have the message and location captured and stored in the
+//! [`Record`], to be reported along with the other results.
+//!
+//! A solution that loops forever can't be stopped safely from the outside, so after the timeout
+//! the thread is abandoned and left to finish in the background. It's killed when the process
+//! exits. Until then it keeps a CPU core busy, which may slow down solutions that run later.
+//! Long running processes such as `serve` pass an [`Abandoned`] counter to [`solve_tracked`] so
+//! that they can refuse new work while too many abandoned threads are still busy.
+use super::record::{self, Record, Status};
+use super::stats::Sampling;
+use crate::solution::Entry;
+use crate::util::md5;
+use std::any::Any;
+use std::cell::RefCell;
+use std::fs::read_to_string;
+use std::panic;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+use std::sync::{mpsc, Arc, Mutex, Once};
+use std::thread;
+use std::time::Duration;
+
+/// Name of every runner thread, used by the panic hook to tell them apart from other threads.
+const THREAD_NAME: &str = "aoc-runner";
+
+thread_local! {
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Number of solutions that timed out but whose threads are still running, shared by clones.
+#[derive(Clone, Debug, Default)]
+pub struct Abandoned(Arc<AtomicUsize>);
+
+impl Abandoned {
+    pub fn count(&self) -> usize {
+        self.0.load(SeqCst)
+    }
+}
+
+/// Whether the caller is still waiting for a runner thread.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    Running,
+    Finished,
+    Abandoned,
+}
+
+/// Reads the input at `path` then solves it on a separate thread, giving up after `timeout`.
+pub fn run(
+    entry: &'static Entry,
+    path: PathBuf,
+    sampling: Sampling,
+    timeout: Option<Duration>,
+) -> Record {
+    match read_to_string(&path) {
+        Ok(data) => solve(entry, path, data, sampling, timeout),
+        Err(_) => Record::new(entry, path, Status::MissingInput),
+    }
+}
+
+/// Solves `data` on a separate thread, giving up after `timeout`.
+pub fn solve(
+    entry: &'static Entry,
+    path: PathBuf,
+    data: String,
+    sampling: Sampling,
+    timeout: Option<Duration>,
+) -> Record {
+    solve_tracked(entry, path, data, sampling, timeout, &Abandoned::default())
+}
+
+/// Same as [`solve`], but counts the thread in `abandoned` from the timeout until it finishes.
+pub fn solve_tracked(
+    entry: &'static Entry,
+    path: PathBuf,
+    data: String,
+    sampling: Sampling,
+    timeout: Option<Duration>,
+    abandoned: &Abandoned,
+) -> Record {
+    install_hook();
+
+    let (sender, receiver) = mpsc::channel();
+    let mut timed_out = Record::new(entry, path.clone(), Status::Timeout);
+    timed_out.hash = Some(md5::to_hex(md5::hash(data.as_bytes())));
+    timed_out.error = timeout.map(|timeout| format!("No answer after {timeout:?}"));
+
+    let phase = Arc::new(Mutex::new(Phase::Running));
+    let (shared, counter) = (phase.clone(), abandoned.clone());
+
+    let spawned = thread::Builder::new().name(THREAD_NAME.to_owned()).spawn(move || {
+        let record = record::solve(entry, path, &data, sampling);
+        let _ = sender.send(record);
+
+        let mut phase = shared.lock().unwrap_or_else(|e| e.into_inner());
+        if *phase == Phase::Abandoned {
+            counter.0.fetch_sub(1, SeqCst);
+        }
+        *phase = Phase::Finished;
+    });
+
+    if let Err(error) = spawned {
+        let mut record = timed_out;
+        record.status = Status::Panicked;
+        record.error = Some(format!("Unable to start thread: {error}"));
+        return record;
+    }
+
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout).ok(),
+        None => receiver.recv().ok(),
+    };
+    received.unwrap_or_else(|| {
+        // The thread may have finished just after the timeout, in which case there's nothing
+        // left running to count.
+        let mut phase = phase.lock().unwrap_or_else(|e| e.into_inner());
+        if *phase == Phase::Running {
+            abandoned.0.fetch_add(1, SeqCst);
+            *phase = Phase::Abandoned;
+        }
+        timed_out
+    })
+}
+
+/// Describes a caught panic, preferring the message and location captured by the panic hook.
+pub fn message(payload: &(dyn Any + Send)) -> String {
+    LAST_PANIC.with(|last| last.borrow_mut().take()).unwrap_or_else(|| {
+        match payload.downcast_ref::<&str>() {
+            Some(message) => (*message).to_owned(),
+            None => payload.downcast_ref::<String>().cloned().unwrap_or_default(),
+        }
+    })
+}
+
+/// Wraps the existing panic hook, so that panics on other threads are reported as usual.
+fn install_hook() {
+    static ONCE: Once = Once::new();
+
+    ONCE.call_once(|| {
+        let default = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() == Some(THREAD_NAME) {
+                let message = info.payload_as_str().unwrap_or("Box<dyn Any>");
+                let message = match info.location() {
+                    Some(location) => format!("{message} at {location}"),
+                    None => message.to_owned(),
+                };
+                LAST_PANIC.with(|last| *last.borrow_mut() = Some(message));
+            } else {
+                default(info);
+            }
+        }));
+    });
+}
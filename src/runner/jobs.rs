@@ -0,0 +1,55 @@
+//! Runs several days at the same time while still reporting them in order.
+//!
+//! Each day is timed on its own, so individual timings remain comparable with a sequential run,
+//! although they can be somewhat slower when days compete for memory bandwidth or when there
+//! are more jobs than CPU cores. Results are passed on in the same order as the items, as soon
+//! as every earlier item has finished, so output is identical no matter which day finishes first.
+use rayon::ThreadPoolBuilder;
+use std::collections::BTreeMap;
+use std::sync::mpsc;
+
+/// Calls `work` for every item, using up to `jobs` threads, then `each` in the original order.
+/// A single job runs everything on the current thread, while zero uses one job per CPU core.
+pub fn run<T, R>(
+    items: &[T],
+    jobs: usize,
+    work: impl Fn(&T) -> R + Sync,
+    mut each: impl FnMut(&T, R),
+) -> Result<(), String>
+where
+    T: Sync,
+    R: Send,
+{
+    if jobs == 1 {
+        items.iter().for_each(|item| each(item, work(item)));
+        return Ok(());
+    }
+
+    let pool = ThreadPoolBuilder::new().num_threads(jobs).build().map_err(|e| e.to_string())?;
+    let (sender, receiver) = mpsc::channel();
+
+    pool.in_place_scope(|scope| {
+        for (index, item) in items.iter().enumerate() {
+            let sender = sender.clone();
+            let work = &work;
+            scope.spawn(move |_| {
+                let _ = sender.send((index, work(item)));
+            });
+        }
+        drop(sender);
+
+        // Hold back results that finish early until all preceding results are done.
+        let mut pending = BTreeMap::new();
+        let mut next = 0;
+
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&next) {
+                each(&items[next], result);
+                next += 1;
+            }
+        }
+    });
+
+    Ok(())
+}
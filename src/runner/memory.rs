@@ -0,0 +1,101 @@
+//! Counts heap allocations, to report how much memory each phase of a solution uses.
+//!
+//! Counting is opt-in with the `memory` feature, for example
+//! `cargo run --release --features memory`, which makes the `aoc` binary install [`Counting`] as
+//! its global allocator. The library never installs an allocator itself, so crates depending on
+//! it keep their own. Without the feature there is no overhead at all and no memory figures are
+//! reported.
+//!
+//! For each phase the number of allocations, the total bytes allocated and the peak live bytes
+//! above what was already in use when the phase started are recorded. A `realloc` counts as a
+//! new allocation of the full new size. The counters are shared by the whole process, so running
+//! several days at once with `--jobs` or a timed out solution still running in the background
+//! inflates the figures.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+/// Wraps the [`System`] allocator, counting every allocation.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            allocated(layout.size() as u64);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            allocated(layout.size() as u64);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        LIVE.fetch_sub(layout.size() as u64, Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new.is_null() {
+            LIVE.fetch_sub(layout.size() as u64, Relaxed);
+            allocated(new_size as u64);
+        }
+        new
+    }
+}
+
+fn allocated(size: u64) {
+    ALLOCATIONS.fetch_add(1, Relaxed);
+    BYTES.fetch_add(size, Relaxed);
+    let live = LIVE.fetch_add(size, Relaxed) + size;
+    PEAK.fetch_max(live, Relaxed);
+}
+
+/// `true` when built with the `memory` feature, so that allocations are being counted.
+pub fn enabled() -> bool {
+    cfg!(feature = "memory")
+}
+
+/// Memory used by a single phase.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: u64,
+    /// Total size of every allocation, including memory that was freed again.
+    pub bytes: u64,
+    /// Largest amount of extra memory in use at any one time.
+    pub peak: u64,
+}
+
+/// Measures the memory used between [`Meter::start`] and [`Meter::stop`].
+pub struct Meter {
+    allocations: u64,
+    bytes: u64,
+    live: u64,
+}
+
+impl Meter {
+    pub fn start() -> Self {
+        let live = LIVE.load(Relaxed);
+        PEAK.store(live, Relaxed);
+        Meter { allocations: ALLOCATIONS.load(Relaxed), bytes: BYTES.load(Relaxed), live }
+    }
+
+    /// Usage since the meter started, or `None` if allocations aren't being counted.
+    pub fn stop(self) -> Option<Usage> {
+        enabled().then(|| Usage {
+            allocations: ALLOCATIONS.load(Relaxed) - self.allocations,
+            bytes: BYTES.load(Relaxed) - self.bytes,
+            peak: PEAK.load(Relaxed).saturating_sub(self.live),
+        })
+    }
+}
@@ -0,0 +1,181 @@
+//! Outcome of running a single registered solution against its input file.
+//!
+//! A [`Record`] is produced for every selected day, even when the input is missing or the
+//! solution panics, so that every output format can report on exactly the same set of days.
+use super::answers::{Expected, Verdict};
+use super::input;
+use super::isolate;
+use super::memory::{Meter, Usage};
+use super::stats::{Sampling, Stats};
+use crate::error::AocError;
+use crate::solution::{Answer, Entry};
+use crate::util::md5;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    MissingInput,
+    InvalidInput,
+    Panicked,
+    Timeout,
+}
+
+impl Status {
+    /// Stable identifier used by machine readable formats.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::MissingInput => "missing_input",
+            Status::InvalidInput => "invalid_input",
+            Status::Panicked => "panicked",
+            Status::Timeout => "timeout",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub path: PathBuf,
+    /// MD5 digest of the input in hexadecimal, matching the output of `md5sum`.
+    pub hash: Option<String>,
+    pub status: Status,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub parse_time: Stats,
+    pub part1_time: Stats,
+    pub part2_time: Stats,
+    /// Only present when built with the `memory` feature, see [`memory`](super::memory).
+    pub parse_memory: Option<Usage>,
+    pub part1_memory: Option<Usage>,
+    pub part2_memory: Option<Usage>,
+    /// Only present when answers have been checked with [`Record::verify`].
+    pub part1_verdict: Option<Verdict>,
+    pub part2_verdict: Option<Verdict>,
+    /// Panic message and location when the solution failed, or why the input was rejected.
+    pub error: Option<String>,
+    /// Changes made to the input before solving, see [`input::check`].
+    pub notes: Vec<String>,
+}
+
+impl Record {
+    /// Creates a record without any answers or timings.
+    pub fn new(entry: &Entry, path: PathBuf, status: Status) -> Self {
+        Record {
+            year: entry.year,
+            day: entry.day,
+            path,
+            hash: None,
+            status,
+            part1: None,
+            part2: None,
+            parse_time: Stats::default(),
+            part1_time: Stats::default(),
+            part2_time: Stats::default(),
+            parse_memory: None,
+            part1_memory: None,
+            part2_memory: None,
+            part1_verdict: None,
+            part2_verdict: None,
+            error: None,
+            notes: Vec::new(),
+        }
+    }
+
+    /// Compares both answers against `expected`. Days without input can't be checked so are
+    /// always unknown, however a panic or timeout counts as a wrong answer.
+    pub fn verify(&mut self, expected: &Expected) {
+        let verdict = |actual, expected| match self.status {
+            Status::MissingInput => Verdict::Unknown,
+            _ => Verdict::new(actual, expected),
+        };
+
+        self.part1_verdict = Some(verdict(self.part1.as_ref(), expected.part1.as_deref()));
+        self.part2_verdict = Some(verdict(self.part2.as_ref(), expected.part2.as_deref()));
+    }
+
+    /// `true` if either part was checked and found to be wrong.
+    pub fn is_wrong(&self) -> bool {
+        [&self.part1_verdict, &self.part2_verdict]
+            .into_iter()
+            .any(|verdict| matches!(verdict, Some(Verdict::Wrong(_))))
+    }
+
+    /// Total of the median time of every phase.
+    pub fn total_time(&self) -> Duration {
+        self.parse_time.median + self.part1_time.median + self.part2_time.median
+    }
+}
+
+/// Runs both parts against `data`, repeating every phase as requested by `sampling`.
+/// A panic in either part is caught and recorded, keeping any answer that was already computed.
+/// Invalid input is rejected without running the solution, as is input that fails to parse.
+pub fn solve(entry: &Entry, path: PathBuf, data: &str, sampling: Sampling) -> Record {
+    let mut record = Record::new(entry, path, Status::Panicked);
+    record.hash = Some(md5::to_hex(md5::hash(data.as_bytes())));
+
+    let data = match input::check(data) {
+        Ok(checked) => {
+            record.notes = checked.notes;
+            checked.data
+        }
+        Err(error) => {
+            record.status = Status::InvalidInput;
+            record.error = Some(error);
+            return record;
+        }
+    };
+    let data = data.as_ref();
+
+    let mut samples = [const { Vec::new() }; 3];
+    let mut memory = [None; 3];
+
+    let result = catch_unwind(AssertUnwindSafe(|| -> Result<(), AocError> {
+        for i in 0..sampling.warmup + sampling.iterations.max(1) {
+            let meter = Meter::start();
+            let instant = Instant::now();
+            let input = (entry.parse)(data)?;
+            let parse_time = instant.elapsed();
+            memory[0] = meter.stop();
+
+            let meter = Meter::start();
+            let instant = Instant::now();
+            record.part1 = Some((entry.part1)(input.as_ref()));
+            let part1_time = instant.elapsed();
+            memory[1] = meter.stop();
+
+            let meter = Meter::start();
+            let instant = Instant::now();
+            record.part2 = Some((entry.part2)(input.as_ref()));
+            let part2_time = instant.elapsed();
+            memory[2] = meter.stop();
+
+            if i >= sampling.warmup {
+                samples[0].push(parse_time);
+                samples[1].push(part1_time);
+                samples[2].push(part2_time);
+            }
+        }
+        Ok(())
+    }));
+
+    match result {
+        Ok(Ok(())) => {
+            record.status = Status::Ok;
+            record.parse_time = Stats::new(&samples[0]);
+            record.part1_time = Stats::new(&samples[1]);
+            record.part2_time = Stats::new(&samples[2]);
+            [record.parse_memory, record.part1_memory, record.part2_memory] = memory;
+        }
+        Ok(Err(error)) => {
+            record.status = Status::InvalidInput;
+            record.error = Some(error.to_string());
+        }
+        Err(payload) => record.error = Some(isolate::message(payload.as_ref())),
+    }
+    record
+}
@@ -0,0 +1,299 @@
+//! Renders the results of a run as a Markdown table and a static HTML page.
+//!
+//! Days are grouped by year, with one table per year listing the puzzle title, stars and the
+//! median time of each phase. The slowest days across the whole run are highlighted, in bold
+//! for Markdown and with the `slow` class for HTML.
+//!
+//! Puzzle titles come from the table of official titles in [`TITLES`], covering every year with
+//! registered solutions. Other days fall back to the `//! # Day 7: Camel Cards` heading at the
+//! top of their source file, as created by `new` from `src/template.rs`, and are left blank if
+//! that still shows the `[Title]` placeholder.
+use super::answers::Verdict;
+use super::format::duration;
+use super::record::{Record, Status};
+use std::fmt::Write;
+use std::fs::read_to_string;
+use std::path::Path;
+
+/// Official puzzle titles for each year, in day order.
+pub const TITLES: &[(u32, &[&str])] = &[
+    (
+        2019,
+        &[
+            "The Tyranny of the Rocket Equation",
+            "1202 Program Alarm",
+            "Crossed Wires",
+            "Secure Container",
+            "Sunny with a Chance of Asteroids",
+            "Universal Orbit Map",
+            "Amplification Circuit",
+            "Space Image Format",
+            "Sensor Boost",
+            "Monitoring Station",
+            "Space Police",
+            "The N-Body Problem",
+            "Care Package",
+            "Space Stoichiometry",
+            "Oxygen System",
+            "Flawed Frequency Transmission",
+            "Set and Forget",
+            "Many-Worlds Interpretation",
+            "Tractor Beam",
+            "Donut Maze",
+            "Springdroid Adventure",
+            "Slam Shuffle",
+            "Category Six",
+            "Planet of Discord",
+            "Cryostasis",
+        ],
+    ),
+    (
+        2023,
+        &[
+            "Trebuchet?!",
+            "Cube Conundrum",
+            "Gear Ratios",
+            "Scratchcards",
+            "If You Give A Seed A Fertilizer",
+            "Wait For It",
+            "Camel Cards",
+            "Haunted Wasteland",
+            "Mirage Maintenance",
+            "Pipe Maze",
+            "Cosmic Expansion",
+            "Hot Springs",
+            "Point of Incidence",
+            "Parabolic Reflector Dish",
+            "Lens Library",
+            "The Floor Will Be Lava",
+            "Clumsy Crucible",
+            "Lavaduct Lagoon",
+            "Aplenty",
+            "Pulse Propagation",
+            "Step Counter",
+            "Sand Slabs",
+            "A Long Walk",
+            "Never Tell Me The Odds",
+            "Snowverload",
+        ],
+    ),
+    (
+        2024,
+        &[
+            "Historian Hysteria",
+            "Red-Nosed Reports",
+            "Mull It Over",
+            "Ceres Search",
+            "Print Queue",
+            "Guard Gallivant",
+            "Bridge Repair",
+            "Resonant Collinearity",
+            "Disk Fragmenter",
+            "Hoof It",
+            "Plutonian Pebbles",
+            "Garden Groups",
+            "Claw Contraption",
+            "Restroom Redoubt",
+            "Warehouse Woes",
+            "Reindeer Maze",
+            "Chronospatial Computer",
+            "RAM Run",
+            "Linen Layout",
+            "Race Condition",
+            "Keypad Conundrum",
+            "Monkey Market",
+            "LAN Party",
+            "Crossed Wires",
+            "Code Chronicle",
+        ],
+    ),
+    (
+        2025,
+        &[
+            "Secret Entrance",
+            "Gift Shop",
+            "Lobby",
+            "Printing Department",
+            "Cafeteria",
+            "Trash Compactor",
+            "Laboratories",
+            "Playground",
+            "Movie Theater",
+            "Factory",
+            "Reactor",
+            "Christmas Tree Farm",
+        ],
+    ),
+];
+
+/// Number of days highlighted unless asked otherwise.
+pub const DEFAULT_SLOWEST: usize = 5;
+
+/// A day's results, with rows expected in year then day order as produced by the runner.
+pub struct Row {
+    pub record: Record,
+    pub title: Option<String>,
+}
+
+/// Title of a puzzle from [`TITLES`], otherwise from its source file within the repository
+/// at `root`.
+pub fn title(root: &Path, year: u32, day: u32) -> Option<String> {
+    let known = TITLES
+        .iter()
+        .find(|&&(y, _)| y == year)
+        .and_then(|(_, titles)| titles.get(usize::try_from(day).ok()?.checked_sub(1)?));
+    if let Some(title) = known {
+        return Some((*title).to_owned());
+    }
+
+    let path = root.join("src").join(format!("year{year}")).join(format!("day{day:02}.rs"));
+    let text = read_to_string(path).ok()?;
+    let heading = text.lines().find_map(|line| line.strip_prefix("//! # "))?.trim();
+    let title = heading.split_once(": ").map_or(heading, |(_, title)| title).trim();
+    (!title.is_empty() && title != "[Title]").then(|| title.to_owned())
+}
+
+/// Stars earned by a day, one per part unless the solution failed or gave a wrong answer.
+pub fn stars(record: &Record) -> usize {
+    if record.status != Status::Ok {
+        return 0;
+    }
+    [&record.part1_verdict, &record.part2_verdict]
+        .into_iter()
+        .filter(|verdict| !matches!(verdict, Some(Verdict::Wrong(_))))
+        .count()
+}
+
+pub fn markdown(rows: &[Row], slowest: usize) -> String {
+    let slow = highlights(rows, slowest);
+    let mut out = String::from("# Results\n\n");
+    let _ = writeln!(out, "{}\n", summary(rows));
+
+    for (year, group) in years(rows) {
+        let _ = writeln!(out, "## {year}\n");
+        out += "| Day | Title | Stars | Parse | Part 1 | Part 2 | Total |\n";
+        out += "| ---: | --- | --- | ---: | ---: | ---: | ---: |\n";
+
+        for (i, row) in group {
+            let title = row.title.as_deref().unwrap_or_default().replace('|', "\\|");
+            let [stars, parse, part1, part2, total] =
+                cells(&row.record).map(|cell| bold(cell, slow[i]));
+            let day = row.record.day;
+            let _ = writeln!(
+                out,
+                "| {day} | {title} | {stars} | {parse} | {part1} | {part2} | {total} |"
+            );
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+pub fn html(rows: &[Row], slowest: usize) -> String {
+    let slow = highlights(rows, slowest);
+    let mut out = String::from(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Results</title>\n<style>\n\
+         body { font-family: sans-serif; margin: 2em; }\n\
+         table { border-collapse: collapse; margin-bottom: 2em; }\n\
+         th, td { padding: 0.25em 0.75em; border-bottom: 1px solid #ddd; }\n\
+         td.time { text-align: right; font-family: monospace; }\n\
+         tr.slow { background: #fde2e2; font-weight: bold; }\n\
+         </style>\n</head>\n<body>\n<h1>Results</h1>\n",
+    );
+    let _ = writeln!(out, "<p>{}</p>", escape(&summary(rows)));
+
+    for (year, group) in years(rows) {
+        let _ = writeln!(out, "<h2>{year}</h2>\n<table>");
+        out += "<tr><th>Day</th><th>Title</th><th>Stars</th><th>Parse</th><th>Part 1</th>\
+                <th>Part 2</th><th>Total</th></tr>\n";
+
+        for (i, row) in group {
+            let class = if slow[i] { " class=\"slow\"" } else { "" };
+            let title = escape(row.title.as_deref().unwrap_or_default());
+            let [stars, parse, part1, part2, total] = cells(&row.record).map(|cell| escape(&cell));
+            let day = row.record.day;
+            let _ = writeln!(
+                out,
+                "<tr{class}><td>{day}</td><td>{title}</td><td>{stars}</td>\
+                 <td class=\"time\">{parse}</td><td class=\"time\">{part1}</td>\
+                 <td class=\"time\">{part2}</td><td class=\"time\">{total}</td></tr>"
+            );
+        }
+        out += "</table>\n";
+    }
+
+    out += "</body>\n</html>\n";
+    out
+}
+
+/// Stars followed by the timings, or the reason a day has no timings.
+fn cells(record: &Record) -> [String; 5] {
+    let reason = match record.status {
+        Status::Ok => None,
+        Status::MissingInput => Some("missing input"),
+        Status::InvalidInput => Some("invalid input"),
+        Status::Panicked => Some("panicked"),
+        Status::Timeout => Some("timeout"),
+    };
+
+    match reason {
+        Some(reason) => {
+            [reason.to_owned(), String::new(), String::new(), String::new(), String::new()]
+        }
+        None => [
+            "⭐".repeat(stars(record)),
+            duration(record.parse_time.median),
+            duration(record.part1_time.median),
+            duration(record.part2_time.median),
+            duration(record.total_time()),
+        ],
+    }
+}
+
+fn summary(rows: &[Row]) -> String {
+    let stars: usize = rows.iter().map(|row| stars(&row.record)).sum();
+    let total = rows
+        .iter()
+        .filter(|row| row.record.status == Status::Ok)
+        .map(|row| row.record.total_time())
+        .sum();
+    format!("{} days, {stars} stars, {} in total.", rows.len(), duration(total))
+}
+
+/// Which rows are among the `slowest` solved days.
+fn highlights(rows: &[Row], slowest: usize) -> Vec<bool> {
+    let mut solved: Vec<_> =
+        (0..rows.len()).filter(|&i| rows[i].record.status == Status::Ok).collect();
+    solved.sort_by_key(|&i| std::cmp::Reverse(rows[i].record.total_time()));
+
+    let mut slow = vec![false; rows.len()];
+    solved.into_iter().take(slowest).for_each(|i| slow[i] = true);
+    slow
+}
+
+/// Rows grouped by year, keeping their index into `rows`.
+fn years(rows: &[Row]) -> Vec<(u32, Vec<(usize, &Row)>)> {
+    let mut years: Vec<(u32, Vec<(usize, &Row)>)> = Vec::new();
+
+    for (i, row) in rows.iter().enumerate() {
+        match years.last_mut() {
+            Some((year, group)) if *year == row.record.year => group.push((i, row)),
+            _ => years.push((row.record.year, vec![(i, row)])),
+        }
+    }
+    years
+}
+
+fn bold(cell: String, slow: bool) -> String {
+    if slow && !cell.is_empty() {
+        format!("**{cell}**")
+    } else {
+        cell
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
@@ -0,0 +1,164 @@
+//! Chooses which days to run from command line arguments.
+//!
+//! Each argument is a term made of a year component, optionally followed by `::` and a day
+//! component. A term with only a component starting with `day` applies to every year. Each
+//! component is a comma separated list of numbers, inclusive ranges or globs, where the `year`
+//! and `day` prefixes are optional after the first element. A term starting with `!` removes
+//! days instead of adding them. With no terms, or only exclusions, every day is a candidate.
+//!
+//! | Argument                      | Selects                                 |
+//! | ----------------------------- | --------------------------------------- |
+//! | `year2024`, `2024`            | Every day of 2024                       |
+//! | `year2024::day01`             | A single day                            |
+//! | `year2024::day01-05`          | Days 1 to 5 of 2024                     |
+//! | `2023,2024`                   | Every day of 2023 and 2024              |
+//! | `day10`                       | Day 10 of every year                    |
+//! | `year202*::day1?`             | Days 10 to 19 of the 2020s              |
+//! | `'!year2024::day24'`          | Everything except 2024 day 24           |
+//!
+//! Globs match against the year as four digits and the day as two digits, using `*` for any
+//! number of digits and `?` for exactly one digit. Shells such as `bash` may need a `!` quoted.
+use std::str::FromStr;
+
+/// Every term from the command line, split into days to include and days to exclude.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Selection {
+    include: Vec<Term>,
+    exclude: Vec<Term>,
+}
+
+impl Selection {
+    pub fn parse<S: AsRef<str>>(args: &[S]) -> Result<Self, String> {
+        let mut selection = Selection::default();
+
+        for arg in args {
+            let arg = arg.as_ref();
+            match arg.strip_prefix('!') {
+                Some(rest) => selection.exclude.push(rest.parse()?),
+                None => selection.include.push(arg.parse()?),
+            }
+        }
+
+        Ok(selection)
+    }
+
+    /// `true` if no terms were given, so every day is selected.
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    pub fn matches(&self, year: u32, day: u32) -> bool {
+        let included = self.include.is_empty() || self.include.iter().any(|t| t.matches(year, day));
+        let excluded = self.exclude.iter().any(|t| t.matches(year, day));
+        included && !excluded
+    }
+}
+
+/// A set of years and a set of days, where an empty set matches anything.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Term {
+    years: Vec<Spec>,
+    days: Vec<Spec>,
+}
+
+impl Term {
+    pub fn matches(&self, year: u32, day: u32) -> bool {
+        let any = |specs: &[Spec], n, width| {
+            specs.is_empty() || specs.iter().any(|s| s.matches(n, width))
+        };
+        any(&self.years, year, 4) && any(&self.days, day, 2)
+    }
+}
+
+impl FromStr for Term {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |reason: String| format!("Invalid selection \"{s}\": {reason}");
+
+        let (years, days) = match s.split_once("::") {
+            Some((years, days)) => (Some(years), Some(days)),
+            None if s.starts_with("day") => (None, Some(s)),
+            None => (Some(s), None),
+        };
+
+        let years =
+            years.map_or(Ok(Vec::new()), |years| component(years, "year")).map_err(error)?;
+        let days = days.map_or(Ok(Vec::new()), |days| component(days, "day")).map_err(error)?;
+        Ok(Term { years, days })
+    }
+}
+
+/// A single element of a component list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Spec {
+    /// Inclusive range, with a single number represented as a range of one.
+    Range(u32, u32),
+    /// Pattern of digits and wildcards.
+    Glob(String),
+}
+
+impl Spec {
+    /// Globs are matched against `n` zero padded to `width` digits.
+    pub fn matches(&self, n: u32, width: usize) -> bool {
+        match self {
+            Spec::Range(start, end) => (*start..=*end).contains(&n),
+            Spec::Glob(pattern) => glob(pattern.as_bytes(), format!("{n:0width$}").as_bytes()),
+        }
+    }
+}
+
+/// Parses a comma separated list, where each element may start with `prefix`.
+fn component(s: &str, prefix: &str) -> Result<Vec<Spec>, String> {
+    if s.contains("::") {
+        return Err("expected at most one \"::\"".to_owned());
+    }
+    s.split(',').map(|element| spec(element, prefix)).collect()
+}
+
+fn spec(element: &str, prefix: &str) -> Result<Spec, String> {
+    let trimmed = element.strip_prefix(prefix).unwrap_or(element);
+
+    if trimmed.is_empty() {
+        return Err(format!("missing {prefix} in \"{element}\""));
+    }
+    if trimmed.contains(['*', '?']) {
+        return if trimmed.bytes().all(|b| b.is_ascii_digit() || b == b'*' || b == b'?') {
+            Ok(Spec::Glob(trimmed.to_owned()))
+        } else {
+            Err(format!("{prefix} pattern \"{element}\" may only contain digits, * and ?"))
+        };
+    }
+
+    let number = |s: &str| {
+        let digits = s.strip_prefix(prefix).unwrap_or(s);
+        match digits.parse() {
+            Ok(n) if digits.bytes().all(|b| b.is_ascii_digit()) => Ok(n),
+            _ => Err(format!("expected a {prefix} number, found \"{s}\"")),
+        }
+    };
+
+    match trimmed.split_once('-') {
+        Some((start, end)) => {
+            let (start, end) = (number(start)?, number(end)?);
+            if start > end {
+                return Err(format!("{prefix} range \"{element}\" is backwards"));
+            }
+            Ok(Spec::Range(start, end))
+        }
+        None => number(element).map(|n| Spec::Range(n, n)),
+    }
+}
+
+/// Matches `*` against any number of characters and `?` against exactly one.
+fn glob(pattern: &[u8], text: &[u8]) -> bool {
+    match (pattern.split_first(), text.split_first()) {
+        (None, None) => true,
+        (Some((b'*', rest)), _) => {
+            glob(rest, text) || (!text.is_empty() && glob(pattern, &text[1..]))
+        }
+        (Some((b'?', rest)), Some((_, text))) => glob(rest, text),
+        (Some((p, rest)), Some((t, text))) => p == t && glob(rest, text),
+        _ => false,
+    }
+}
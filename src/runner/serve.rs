@@ -0,0 +1,219 @@
+//! Serves the solutions over HTTP, so that other tools can use them without linking the crate.
+//!
+//! Only the standard library is used, speaking just enough HTTP/1.1 for simple clients such as
+//! `curl`:
+//!
+//! * `GET /` lists every registered solution, for example
+//!   `[{"year":2023,"day":7,"path":"/year2023/day07"},...]`.
+//! * `POST /yearYYYY/dayDD` solves the request body as the puzzle input. The reply is the same
+//!   JSON object as a single entry of `--format json`, including both answers and timings.
+//!
+//! A fixed number of worker threads each accept and handle one connection at a time, so a flood
+//! of requests waits in the listen backlog instead of starting unbounded threads. Requests are
+//! solved with [`isolate::solve_tracked`], so a panic or an infinite loop in a solution is
+//! reported in the reply instead of stopping the server. A timed out solution keeps running in
+//! the background, so once [`MAX_ABANDONED`] of them are still busy new solves are refused with
+//! `503` until one finishes.
+//! The status code is `200` when solved, `422` for invalid input, `500` when the solution
+//! panicked and `504` for a timeout. Any other problem is a `4xx` status with a body such as
+//! `{"error":"No solution for 2023 Day 26"}`.
+use super::format::{json, json_string};
+use super::isolate::{self, Abandoned};
+use super::record::Status;
+use super::stats::Sampling;
+use crate::solution::{find, solutions};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+/// Largest accepted puzzle input, comfortably above any real input.
+pub const MAX_BODY: usize = 1024 * 1024;
+
+/// Most timed out solutions left running in the background before new solves are refused.
+pub const MAX_ABANDONED: usize = 4;
+
+const MAX_HEAD: u64 = 64 * 1024;
+const IO_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Handles connections forever on `workers` threads, or one per CPU core for zero, only
+/// returning once the listener fails.
+pub fn serve(
+    listener: TcpListener,
+    workers: usize,
+    sampling: Sampling,
+    timeout: Option<Duration>,
+) -> String {
+    let workers = match workers {
+        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n,
+    };
+    let abandoned = Abandoned::default();
+    let worker = || loop {
+        match listener.accept() {
+            Ok((stream, _)) => connection(stream, sampling, timeout, &abandoned),
+            Err(error) => return format!("Unable to accept connection: {error}"),
+        }
+    };
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers).map(|_| scope.spawn(worker)).collect();
+        let mut messages = handles.into_iter().filter_map(|handle| handle.join().ok());
+        messages.next().unwrap_or_else(|| "Listener closed".to_owned())
+    })
+}
+
+/// Replies to a single request given its method, path and body, counting solves that time out
+/// in `abandoned`.
+pub fn respond(
+    method: &str,
+    path: &str,
+    body: String,
+    sampling: Sampling,
+    timeout: Option<Duration>,
+    abandoned: &Abandoned,
+) -> (u16, String) {
+    if path == "/" {
+        return match method {
+            "GET" => (200, list()),
+            _ => error(405, &format!("{method} is not allowed for /, use GET")),
+        };
+    }
+
+    let Some((year, day)) = route(path) else {
+        return error(404, &format!("Unknown path {path}, expected /yearYYYY/dayDD"));
+    };
+    let Some(entry) = find(year, day) else {
+        return error(404, &format!("No solution for {year} Day {day:02}"));
+    };
+    if method != "POST" {
+        return error(405, &format!("{method} is not allowed for {path}, use POST"));
+    }
+
+    if abandoned.count() >= MAX_ABANDONED {
+        let message = format!("{MAX_ABANDONED} timed out solutions are still running, try later");
+        return error(503, &message);
+    }
+
+    let path = PathBuf::from(path);
+    let record = isolate::solve_tracked(entry, path, body, sampling, timeout, abandoned);
+    let status = match record.status {
+        Status::Ok => 200,
+        Status::MissingInput => 400,
+        Status::InvalidInput => 422,
+        Status::Panicked => 500,
+        Status::Timeout => 504,
+    };
+    (status, json(&record))
+}
+
+fn connection(
+    mut stream: TcpStream,
+    sampling: Sampling,
+    timeout: Option<Duration>,
+    abandoned: &Abandoned,
+) {
+    let _ = stream.set_read_timeout(Some(IO_TIMEOUT));
+    let _ = stream.set_write_timeout(Some(IO_TIMEOUT));
+
+    let (status, body) = match request(&mut stream) {
+        Ok((method, path, body)) => respond(&method, &path, body, sampling, timeout, abandoned),
+        Err((status, message)) => error(status, &message),
+    };
+    let response = format!(
+        "HTTP/1.1 {status} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+         Connection: close\r\n\r\n{body}",
+        reason(status),
+        body.len()
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
+
+/// Reads the method, path and body of a request.
+fn request(stream: &mut TcpStream) -> Result<(String, String, String), (u16, String)> {
+    let bad = |message: &str| (400, message.to_owned());
+    let mut reader = BufReader::new(stream);
+    let mut head = (&mut reader).take(MAX_HEAD);
+    let mut line = String::new();
+
+    head.read_line(&mut line).map_err(|_| bad("Unable to read request"))?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Err(bad("Malformed request line"));
+    };
+    let (method, path) = (method.to_owned(), path.to_owned());
+    let mut length = None;
+
+    loop {
+        line.clear();
+        head.read_line(&mut line).map_err(|_| bad("Unable to read headers"))?;
+        if !line.ends_with('\n') {
+            return Err((431, "Request headers are too large".to_owned()));
+        }
+        if line.trim_end().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = Some(value.trim().parse().map_err(|_| bad("Invalid Content-Length"))?);
+            }
+        }
+    }
+
+    let length = match length {
+        Some(length) if length > MAX_BODY => {
+            return Err((413, format!("Input is larger than {MAX_BODY} bytes")));
+        }
+        Some(length) => length,
+        None if method == "POST" => return Err((411, "Content-Length is required".to_owned())),
+        None => 0,
+    };
+
+    // Grow the buffer as data arrives rather than trusting the claimed length up front.
+    let mut body = Vec::new();
+    let read = reader.take(length as u64).read_to_end(&mut body);
+    if read.is_err() || body.len() < length {
+        return Err(bad("Request body is shorter than expected"));
+    }
+    let body = String::from_utf8(body).map_err(|_| bad("Input must be UTF-8"))?;
+    Ok((method, path, body))
+}
+
+/// Extracts the year and day from a path such as `/year2023/day07`.
+fn route(path: &str) -> Option<(u32, u32)> {
+    let (year, day) = path.strip_prefix("/year")?.trim_end_matches('/').split_once("/day")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+fn list() -> String {
+    let entries: Vec<_> = solutions()
+        .iter()
+        .map(|entry| {
+            let (year, day) = (entry.year, entry.day);
+            format!("{{\"year\":{year},\"day\":{day},\"path\":\"/year{year}/day{day:02}\"}}")
+        })
+        .collect();
+    format!("[{}]", entries.join(","))
+}
+
+fn error(status: u16, message: &str) -> (u16, String) {
+    (status, format!("{{\"error\":{}}}", json_string(message)))
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Content Too Large",
+        422 => "Unprocessable Content",
+        431 => "Request Header Fields Too Large",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "Unknown",
+    }
+}
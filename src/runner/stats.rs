@@ -0,0 +1,50 @@
+//! Summarizes repeated timings of the same phase.
+//!
+//! A single measurement of a sub-millisecond solution is dominated by noise such as cold caches
+//! or the CPU changing frequency. Running each phase several times, optionally discarding some
+//! warmup runs, and reporting the median gives numbers that are stable from run to run.
+use std::time::Duration;
+
+/// How many times to run each solution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sampling {
+    /// Untimed runs before measuring.
+    pub warmup: usize,
+    /// Timed runs, always at least one.
+    pub iterations: usize,
+}
+
+impl Default for Sampling {
+    fn default() -> Self {
+        Sampling { warmup: 0, iterations: 1 }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// 95th percentile using the nearest rank method.
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Stats::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let n = sorted.len();
+        let median =
+            if n % 2 == 1 { sorted[n / 2] } else { (sorted[n / 2 - 1] + sorted[n / 2]) / 2 };
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        let p95 = sorted[(95 * n).div_ceil(100) - 1];
+
+        Stats { samples: n, min: sorted[0], median, mean, p95 }
+    }
+}
@@ -0,0 +1,297 @@
+//! Creates and registers the files for a new day.
+//!
+//! A day needs a solution module `src/yearYYYY/dayDD.rs` registered in the [`solutions!`]
+//! invocation in `src/lib.rs`, plus a test file `tests/yearYYYY/dayDD.rs` registered in the
+//! matching `test!` invocation in `tests/test.rs`. The module starts as a copy of
+//! `src/template.rs`. The tests are marked [`UNFINISHED`] and ignored until the example and its
+//! answers are filled in, so that a new day never looks tested before any code exists.
+//!
+//! Every step is skipped if it has already been done, so running the same command twice is
+//! harmless, and existing files are never overwritten.
+use crate::site::fetch::{days, FIRST_YEAR};
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::Path;
+
+const TEMPLATE: &str = include_str!("template.rs");
+
+/// Reason given by the `#[ignore]` of newly created tests, removed once they are filled in.
+pub const UNFINISHED: &str = "Fill in EXAMPLE and its answer";
+
+/// Column limit used by `rustfmt`.
+const WIDTH: usize = 100;
+
+/// Parses `yearYYYY::dayDD`, with the `year` and `day` prefixes being optional.
+pub fn parse_day(s: &str) -> Result<(u32, u32), String> {
+    let error = || format!("Expected a single day such as year2024::day01, found \"{s}\"");
+    let (year, day) = s.split_once("::").ok_or_else(error)?;
+    let number = |s: &str, prefix| s.strip_prefix(prefix).unwrap_or(s).parse::<u32>().ok();
+    let (year, day) = number(year, "year").zip(number(day, "day")).ok_or_else(error)?;
+
+    if year < FIRST_YEAR || !(1..=days(year)).contains(&day) {
+        return Err(format!("{year} Day {day:02} is not an Advent of Code puzzle"));
+    }
+    Ok((year, day))
+}
+
+/// Creates any missing files and registrations under `root`, describing each change made.
+pub fn scaffold(root: &Path, year: u32, day: u32) -> Result<Vec<String>, String> {
+    let lib = root.join("src").join("lib.rs");
+    let tests = root.join("tests").join("test.rs");
+    if !lib.exists() || !tests.exists() {
+        return Err(format!("{} is not the repository root", root.display()));
+    }
+
+    let module = format!("year{year}/day{day:02}.rs");
+    let mut changes = Vec::new();
+
+    if create(&root.join("src").join(&module), &solution(year, day))? {
+        changes.push(format!("Created src/{module}"));
+    }
+    if create(&root.join("tests").join(&module), &test(year, day))? {
+        changes.push(format!("Created tests/{module}"));
+    }
+    if update(&lib, |text| register(text, year, day, Kind::Solutions))? {
+        changes.push(format!("Registered year{year}::day{day:02} in src/lib.rs"));
+    }
+    if update(&tests, |text| register(text, year, day, Kind::Tests))? {
+        changes.push(format!("Registered year{year}::day{day:02} in tests/test.rs"));
+    }
+
+    Ok(changes)
+}
+
+/// Source for a new solution module.
+pub fn solution(year: u32, day: u32) -> String {
+    TEMPLATE.replacen("Day N", &format!("Day {day}"), 1).replacen(
+        "yearYYYY::dayDD",
+        &format!("year{year}::day{day:02}"),
+        1,
+    )
+}
+
+/// Source for a new test file, ignored until the example and its answers are filled in.
+pub fn test(year: u32, day: u32) -> String {
+    format!(
+        "use aoc::year{year}::day{day:02}::*;
+
+const EXAMPLE: &str = \"\\
+\";
+
+#[test]
+#[ignore = \"{UNFINISHED}\"]
+fn part1_test() {{
+    assert_eq!(part1(EXAMPLE), 0);
+}}
+
+#[test]
+#[ignore = \"{UNFINISHED}\"]
+fn part2_test() {{
+    assert_eq!(part2(EXAMPLE), 0);
+}}
+"
+    )
+}
+
+/// Which macro invocation to add the day to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// `year2024 "Description" day01, day02;` inside `solutions!` in `src/lib.rs`.
+    Solutions,
+    /// `test!(year2024 day01, day02);` in `tests/test.rs`.
+    Tests,
+}
+
+/// Adds `day` to the list for `year`, returning `None` if it's already there. A commented out
+/// year is enabled with just the new day, and a missing year is added after the last year.
+pub fn register(text: &str, year: u32, day: u32, kind: Kind) -> Result<Option<String>, String> {
+    let lines: Vec<_> = text.lines().collect();
+    let name = format!("day{day:02}");
+    let header = match kind {
+        Kind::Solutions => format!("year{year} \""),
+        Kind::Tests => format!("test!(year{year}"),
+    };
+    let error = || match kind {
+        Kind::Solutions => "Unable to find the solutions! invocation in src/lib.rs".to_owned(),
+        Kind::Tests => "Unable to find any test! invocations in tests/test.rs".to_owned(),
+    };
+
+    // Lines to replace and the new block that replaces them.
+    let (range, block) =
+        if let Some(start) = lines.iter().position(|line| line.trim_start().starts_with(&header)) {
+            let stop = (start..lines.len()).find(|&i| closes(lines[i], kind)).ok_or_else(error)?;
+            let (mut days, extra) = items(&lines[start..=stop], kind);
+
+            if days.iter().any(|item| item.split(':').next() == Some(name.as_str())) {
+                return Ok(None);
+            }
+            days.push(name);
+            days.sort_by(|a, b| a.split(':').next().cmp(&b.split(':').next()));
+            (start..stop + 1, render(lines[start], &days, &extra, kind))
+        } else if let Some(start) =
+            lines.iter().position(|line| uncomment(line).is_some_and(|l| l.starts_with(&header)))
+        {
+            let stop = (start..lines.len())
+                .take_while(|&i| uncomment(lines[i]).is_some())
+                .find(|&i| uncomment(lines[i]).is_some_and(|line| closes(line, kind)))
+                .ok_or_else(error)?;
+            let first = format!("    {}", uncomment(lines[start]).unwrap_or_default());
+            let first = if kind == Kind::Tests { first.trim_start() } else { &first };
+            (start..stop + 1, render(first, &[name], &[], kind))
+        } else {
+            // Add a new year after the closest earlier year, along with any comments that follow
+            // that year's block, but before the separator of the next block.
+            let previous = lines
+                .iter()
+                .rposition(|line| header_year(line, kind).is_some_and(|y| y < year))
+                .ok_or_else(error)?;
+            let mut last = (previous..lines.len())
+                .find(|&i| closes(uncomment(lines[i]).unwrap_or(lines[i]), kind))
+                .ok_or_else(error)?;
+            while let Some(&next) = lines.get(last + 1).filter(|line| uncomment(line).is_some()) {
+                let after = lines.get(last + 2).copied().unwrap_or_default();
+                if header_year(next, kind).is_some()
+                    || (next.trim() == "//" && header_year(after, kind).is_some())
+                {
+                    break;
+                }
+                last += 1;
+            }
+
+            let first = match kind {
+                Kind::Solutions => format!("    year{year} \"Advent of Code {year}\""),
+                Kind::Tests => header.clone(),
+            };
+            let separator = if kind == Kind::Solutions { "" } else { "//" };
+            let mut block = vec![separator.to_owned()];
+            block.extend(render(&first, &[name], &[], kind));
+            (last + 1..last + 1, block)
+        };
+
+    let mut out: Vec<_> = lines[..range.start].iter().map(|&line| line.to_owned()).collect();
+    out.extend(block);
+    out.extend(lines[range.end..].iter().map(|&line| line.to_owned()));
+    Ok(Some(out.join("\n") + "\n"))
+}
+
+/// Year of a block's first line, such as `test!(year2024` or `year2024 "Description"`, whether
+/// or not it's commented out.
+fn header_year(line: &str, kind: Kind) -> Option<u32> {
+    let line = uncomment(line).unwrap_or(line.trim_start());
+    let year = match kind {
+        Kind::Solutions => line.strip_prefix("year")?.split_once(" \"")?.0,
+        Kind::Tests => line.strip_prefix("test!(year")?.trim_end(),
+    };
+    year.parse().ok()
+}
+
+/// Text after the `//` of a comment line.
+fn uncomment(line: &str) -> Option<&str> {
+    line.trim_start().strip_prefix("//").map(str::trim_start)
+}
+
+/// `true` for the line that closes a block.
+fn closes(line: &str, kind: Kind) -> bool {
+    match kind {
+        Kind::Solutions => line.trim_end().ends_with(';'),
+        Kind::Tests => line.trim() == ");",
+    }
+}
+
+/// Formats a block with the days wrapped to fit within the column limit.
+fn render(first: &str, days: &[String], extra: &[String], kind: Kind) -> Vec<String> {
+    let indent = if kind == Kind::Solutions { "        " } else { "    " };
+    let mut block = vec![first.to_owned()];
+    let mut line = String::new();
+
+    for (i, day) in days.iter().enumerate() {
+        let piece = if i + 1 < days.len() { format!("{day},") } else { day.clone() };
+        if !line.is_empty() && indent.len() + line.len() + 1 + piece.len() > WIDTH {
+            block.push(format!("{indent}{line}"));
+            line.clear();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line += &piece;
+    }
+    block.push(format!("{indent}{line}"));
+
+    match kind {
+        Kind::Solutions => {
+            block.extend(extra.iter().map(|with| format!("{indent}{with}")));
+            if let Some(last) = block.last_mut() {
+                last.push(';');
+            }
+        }
+        Kind::Tests => {
+            block.extend(extra.iter().cloned());
+            block.push(");".to_owned());
+        }
+    }
+
+    block
+}
+
+/// Splits a block into its days and anything else that should be kept, which is the `with`
+/// clause for solutions and any comments for tests.
+fn items(block: &[&str], kind: Kind) -> (Vec<String>, Vec<String>) {
+    let mut days = Vec::new();
+    let mut extra = Vec::new();
+
+    for (i, line) in block.iter().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("//") {
+            if kind == Kind::Tests {
+                extra.push((*line).to_owned());
+            }
+            continue;
+        }
+
+        let content = match kind {
+            // The first line holds the year and description.
+            _ if i == 0 => continue,
+            Kind::Solutions => trimmed.trim_end_matches(';'),
+            Kind::Tests => trimmed.trim_end_matches(");"),
+        };
+        let (list, with) = match content.split_once("with ") {
+            Some((list, with)) => (list, Some(with)),
+            None => (content, None),
+        };
+
+        days.extend(list.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_owned));
+        if let Some(with) = with {
+            extra.push(format!("with {}", with.trim()));
+        }
+    }
+
+    (days, extra)
+}
+
+/// Writes `contents` to a new file, returning `false` if the file already exists.
+fn create(path: &Path, contents: &str) -> Result<bool, String> {
+    if path.exists() {
+        return Ok(false);
+    }
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)
+            .map_err(|e| format!("Unable to create {}: {e}", parent.display()))?;
+    }
+    write(path, contents).map_err(|e| format!("Unable to write {}: {e}", path.display()))?;
+    Ok(true)
+}
+
+/// Applies `edit` to the file at `path`, returning `false` if nothing changed.
+fn update(
+    path: &Path,
+    edit: impl Fn(&str) -> Result<Option<String>, String>,
+) -> Result<bool, String> {
+    let text =
+        read_to_string(path).map_err(|e| format!("Unable to read {}: {e}", path.display()))?;
+    match edit(&text)? {
+        Some(text) => {
+            write(path, text).map_err(|e| format!("Unable to write {}: {e}", path.display()))?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
@@ -0,0 +1,78 @@
+//! Authenticated and throttled access to the Advent of Code website.
+//!
+//! Inputs are different for every user, so requests need the `session` cookie from a logged in
+//! browser. The token is read from the `AOC_SESSION` environment variable if set, otherwise
+//! from the first line of `$XDG_CONFIG_HOME/aoc/session`, falling back to
+//! `~/.config/aoc/session`. Either form `53616c74...` or `session=53616c74...` is accepted.
+//!
+//! To be polite to the server, consecutive requests from the same [`Client`] are spaced at
+//! least `delay` apart.
+use super::http::{self, Request, Response};
+use std::env;
+use std::fs::read_to_string;
+use std::path::PathBuf;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_DELAY: Duration = Duration::from_secs(1);
+/// Environment variable holding the session token.
+pub const SESSION: &str = "AOC_SESSION";
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    delay: Duration,
+    last: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, delay: Duration) -> Self {
+        let base_url = base_url.trim_end_matches('/').to_owned();
+        let session = session.trim().trim_start_matches("session=").to_owned();
+        Client { base_url, session, delay, last: None }
+    }
+
+    /// `GET` of a path relative to the base URL, for example `/2024/day/1/input`.
+    pub fn get(&mut self, path: &str) -> Result<Response, String> {
+        self.send(path, None)
+    }
+
+    /// `POST` of an already encoded form to a path relative to the base URL.
+    pub fn post(&mut self, path: &str, form: &str) -> Result<Response, String> {
+        self.send(path, Some(form))
+    }
+
+    fn send(&mut self, path: &str, form: Option<&str>) -> Result<Response, String> {
+        if let Some(wait) = self.last.and_then(|last| self.delay.checked_sub(last.elapsed())) {
+            sleep(wait);
+        }
+        self.last = Some(Instant::now());
+
+        let url = format!("{}{path}", self.base_url);
+        let cookie = format!("session={}", self.session);
+        http::send(&Request { url: &url, cookie: Some(&cookie), form })
+    }
+}
+
+/// Finds the session token from the environment or the config file.
+pub fn session() -> Result<String, String> {
+    if let Some(token) = env::var(SESSION).ok().filter(|token| !token.trim().is_empty()) {
+        return Ok(token);
+    }
+
+    let path = config_dir().map(|dir| dir.join("aoc").join("session"));
+    path.as_ref()
+        .and_then(|path| read_to_string(path).ok())
+        .and_then(|text| text.lines().next().map(str::to_owned))
+        .filter(|token| !token.trim().is_empty())
+        .ok_or_else(|| {
+            let path = path.map_or("~/.config/aoc/session".to_owned(), |p| p.display().to_string());
+            format!("No session token, set {SESSION} or save the token in {path}")
+        })
+}
+
+fn config_dir() -> Option<PathBuf> {
+    let xdg = env::var_os("XDG_CONFIG_HOME").filter(|dir| !dir.is_empty()).map(PathBuf::from);
+    xdg.or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+}
@@ -0,0 +1,91 @@
+//! Downloads puzzle inputs into the layout expected by the runner.
+//!
+//! Inputs never change once published, so a file that already exists is never downloaded again.
+//! Only successful responses that pass [`input::check`] are saved, anything else such as an
+//! error page or a request to log in is reported instead of being written to disk.
+use super::client::Client;
+use crate::runner::input;
+use std::fs::{create_dir_all, rename, write};
+use std::path::Path;
+
+/// The first year of Advent of Code.
+pub const FIRST_YEAR: u32 = 2015;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Cached,
+    Downloaded,
+}
+
+/// Number of puzzles in `year`, which dropped from 25 to 12 starting in 2025.
+pub fn days(year: u32) -> u32 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// Every puzzle from the first day of the first year up to and including `last`, in order.
+pub fn calendar(last: (u32, u32)) -> impl Iterator<Item = (u32, u32)> {
+    let (last_year, last_day) = last;
+    (FIRST_YEAR..=last_year)
+        .flat_map(|year| (1..=days(year)).map(move |day| (year, day)))
+        .take_while(move |&(year, day)| year < last_year || day <= last_day)
+}
+
+/// Latest puzzle already released at `now` seconds since the Unix epoch. Puzzles unlock at
+/// midnight US Eastern Standard Time (UTC-5) from the 1st of December onwards.
+pub fn unlocked(now: u64) -> (u32, u32) {
+    let (year, month, day) = civil(now.saturating_sub(5 * 3600) / 86_400);
+
+    if month == 12 {
+        (year, day.min(days(year)))
+    } else {
+        (year - 1, days(year - 1))
+    }
+}
+
+/// Converts days since the Unix epoch to a `(year, month, day)` date in the Gregorian calendar,
+/// using Howard Hinnant's [`civil_from_days`](https://howardhinnant.github.io/date_algorithms.html).
+fn civil(days: u64) -> (u32, u32, u32) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year as u32, month as u32, day as u32)
+}
+
+/// Downloads the input for `year` and `day` to `path` unless the file already exists.
+pub fn fetch(client: &mut Client, year: u32, day: u32, path: &Path) -> Result<Outcome, String> {
+    if path.exists() {
+        return Ok(Outcome::Cached);
+    }
+
+    let response = client.get(&format!("/{year}/day/{day}/input"))?;
+    let first = response.body.lines().next().unwrap_or_default().trim();
+
+    if response.status != 200 {
+        return Err(format!("Server replied with status {}: {first}", response.status));
+    }
+    input::check(&response.body)
+        .map_err(|error| format!("Server replied with bad input: {error}"))?;
+
+    // Write to a temporary file first so that an interrupted download is never mistaken for
+    // a cached input.
+    let error = |e: std::io::Error| format!("Unable to save {}: {e}", path.display());
+    let partial = path.with_extension("part");
+
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent).map_err(error)?;
+    }
+    write(&partial, &response.body).map_err(error)?;
+    rename(&partial, path).map_err(error)?;
+
+    Ok(Outcome::Downloaded)
+}
@@ -0,0 +1,153 @@
+//! Just enough HTTP/1.1 to download inputs and submit answers.
+//!
+//! Plain `http://` URLs are handled directly with [`TcpStream`], which is also what tests use
+//! with a local stand-in server. The standard library has no TLS support, so `https://` URLs are
+//! handed to the `curl` command line tool instead. The request headers, including the session
+//! cookie, are passed to `curl` on stdin so that they don't show up in the process list.
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// Identifies the tool to the server, as requested by the Advent of Code maintainers.
+pub const USER_AGENT: &str = "github.com/jkryspin/advent-of-code-rust";
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request<'a> {
+    pub url: &'a str,
+    pub cookie: Option<&'a str>,
+    /// Sent as `application/x-www-form-urlencoded`, turning the request into a `POST`.
+    pub form: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+pub fn send(request: &Request<'_>) -> Result<Response, String> {
+    if let Some(rest) = request.url.strip_prefix("http://") {
+        plain(request, rest)
+    } else if request.url.starts_with("https://") {
+        curl(request)
+    } else {
+        Err(format!("Unsupported URL {}, expected http:// or https://", request.url))
+    }
+}
+
+/// Percent encodes a form value.
+pub fn encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+fn plain(request: &Request<'_>, rest: &str) -> Result<Response, String> {
+    let (host, path) = rest.find('/').map_or((rest, "/"), |i| (&rest[..i], &rest[i..]));
+    let address = if host.contains(':') { host.to_owned() } else { format!("{host}:80") };
+    let error = |e: std::io::Error| format!("Request to {} failed: {e}", request.url);
+
+    let mut stream = TcpStream::connect(&address).map_err(error)?;
+    stream.set_read_timeout(Some(TIMEOUT)).map_err(error)?;
+    stream.set_write_timeout(Some(TIMEOUT)).map_err(error)?;
+
+    let method = if request.form.is_some() { "POST" } else { "GET" };
+    let mut head = format!(
+        "{method} {path} HTTP/1.1\r\nHost: {host}\r\nUser-Agent: {USER_AGENT}\r\n\
+         Connection: close\r\n"
+    );
+    if let Some(cookie) = request.cookie {
+        head += &format!("Cookie: {cookie}\r\n");
+    }
+    if let Some(form) = request.form {
+        head += "Content-Type: application/x-www-form-urlencoded\r\n";
+        head += &format!("Content-Length: {}\r\n", form.len());
+    }
+    head += "\r\n";
+
+    stream.write_all(head.as_bytes()).map_err(error)?;
+    stream.write_all(request.form.unwrap_or_default().as_bytes()).map_err(error)?;
+
+    let mut raw = Vec::new();
+    stream.read_to_end(&mut raw).map_err(error)?;
+    parse(&raw).ok_or_else(|| format!("Malformed response from {}", request.url))
+}
+
+/// Parses a raw response, including bodies sent with chunked transfer encoding.
+fn parse(raw: &[u8]) -> Option<Response> {
+    let split = raw.windows(4).position(|w| w == b"\r\n\r\n")?;
+    let head = std::str::from_utf8(&raw[..split]).ok()?;
+    let body = &raw[split + 4..];
+
+    let mut lines = head.split("\r\n");
+    let status = lines.next()?.split_whitespace().nth(1)?.parse().ok()?;
+    let chunked = lines.any(|line| {
+        let (name, value) = line.split_once(':').unwrap_or_default();
+        name.eq_ignore_ascii_case("transfer-encoding") && value.trim() == "chunked"
+    });
+
+    let body = if chunked { dechunk(body)? } else { body.to_vec() };
+    Some(Response { status, body: String::from_utf8_lossy(&body).into_owned() })
+}
+
+fn dechunk(mut body: &[u8]) -> Option<Vec<u8>> {
+    let mut out = Vec::new();
+
+    loop {
+        let end = body.windows(2).position(|w| w == b"\r\n")?;
+        let size = std::str::from_utf8(&body[..end]).ok()?;
+        let size = usize::from_str_radix(size.split(';').next()?.trim(), 16).ok()?;
+        if size == 0 {
+            return Some(out);
+        }
+
+        let chunk = body.get(end + 2..end + 2 + size)?;
+        out.extend_from_slice(chunk);
+        body = body.get(end + 4 + size..)?;
+    }
+}
+
+fn curl(request: &Request<'_>) -> Result<Response, String> {
+    let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+
+    let mut config = format!("url = {}\nuser-agent = {}\n", quote(request.url), quote(USER_AGENT));
+    if let Some(cookie) = request.cookie {
+        config += &format!("cookie = {}\n", quote(cookie));
+    }
+    if let Some(form) = request.form {
+        config += &format!("data = {}\n", quote(form));
+    }
+
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--max-time", "30", "--config", "-"])
+        .args(["--write-out", "\n%{http_code}"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Unable to run curl, which is needed for https: {e}"))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(config.as_bytes()).map_err(|e| e.to_string())?;
+    }
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Request to {} failed: {}", request.url, stderr.trim()));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = stdout.rsplit_once('\n').unwrap_or(("", &stdout));
+    let status = status.trim().parse().map_err(|_| "Malformed response from curl".to_owned())?;
+    Ok(Response { status, body: body.to_owned() })
+}
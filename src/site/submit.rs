@@ -0,0 +1,185 @@
+//! Submits answers and remembers the outcome.
+//!
+//! The website replies with an HTML page containing a sentence or two of prose, which
+//! [`parse`] turns into a [`Reply`]. Correct answers are saved as the expected answer for use
+//! with `--verify`, while rejected answers and any cooldown are saved as [`Submissions`] so that
+//! the same mistake is never sent twice. See [`answers`] for the file format.
+use super::client::Client;
+use super::http;
+use crate::runner::answers::{self, Expected, Submissions};
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Reply {
+    Correct,
+    /// Wrong answers also lock out further submissions for a short time.
+    Wrong {
+        hint: Hint,
+        wait: Option<Duration>,
+    },
+    /// Submitted too soon after a previous answer.
+    Wait(Duration),
+    /// The part was already solved, or part one needs solving first.
+    WrongLevel,
+    /// Anything else, holding the text of the reply.
+    Unknown(String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+    None,
+}
+
+impl fmt::Display for Reply {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reply::Correct => write!(f, "That's the right answer!"),
+            Reply::Wrong { hint, wait } => {
+                write!(f, "That's not the right answer")?;
+                match hint {
+                    Hint::TooHigh => write!(f, ", it's too high")?,
+                    Hint::TooLow => write!(f, ", it's too low")?,
+                    Hint::None => (),
+                }
+                match wait {
+                    Some(wait) => write!(f, ". Wait {}s before trying again.", wait.as_secs()),
+                    None => write!(f, "."),
+                }
+            }
+            Reply::Wait(wait) => write!(f, "Answered too recently, wait {}s.", wait.as_secs()),
+            Reply::WrongLevel => write!(f, "Not the right level, was it already solved?"),
+            Reply::Unknown(text) => write!(f, "Unexpected reply: {text}"),
+        }
+    }
+}
+
+/// Checks `answer` against what's already known, then submits it and records the reply
+/// in `store`. `now` is the current time in seconds since the Unix epoch.
+pub fn submit(
+    client: &mut Client,
+    (year, day, part): (u32, u32, usize),
+    answer: &str,
+    store: &Path,
+    now: u64,
+) -> Result<Reply, String> {
+    let text = answers::read(store);
+    let expected = Expected::parse(&text);
+    let known = if part == 1 { expected.part1 } else { expected.part2 };
+
+    match known {
+        Some(known) if known == answer => {
+            return Err(format!("{answer} is already known to be correct for part {part}"));
+        }
+        Some(known) => return Err(format!("Part {part} was already solved with {known}")),
+        None => Submissions::parse(&text).check(part, answer, now)?,
+    }
+
+    let form = format!("level={part}&answer={}", http::encode(answer));
+    let response = client.post(&format!("/{year}/day/{day}/answer"), &form)?;
+    if response.status != 200 {
+        return Err(format!("Server replied with status {}", response.status));
+    }
+
+    let reply = parse(&response.body);
+    let key = |suffix| format!("part{part}{suffix}");
+    let wait_until = |wait: &Duration| (now + wait.as_secs()).to_string();
+
+    match &reply {
+        Reply::Correct => answers::append(store, &key(""), answer)?,
+        Reply::Wrong { hint, wait } => {
+            let suffix = match hint {
+                Hint::TooHigh => "_too_high",
+                Hint::TooLow => "_too_low",
+                Hint::None => "_wrong",
+            };
+            answers::append(store, &key(suffix), answer)?;
+            if let Some(wait) = wait {
+                answers::append(store, "wait_until", &wait_until(wait))?;
+            }
+        }
+        Reply::Wait(wait) => answers::append(store, "wait_until", &wait_until(wait))?,
+        Reply::WrongLevel | Reply::Unknown(_) => (),
+    }
+
+    Ok(reply)
+}
+
+/// Interprets the HTML page sent in reply to an answer.
+pub fn parse(body: &str) -> Reply {
+    let text = article(body);
+
+    if text.contains("That's the right answer") {
+        Reply::Correct
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("too high") {
+            Hint::TooHigh
+        } else if text.contains("too low") {
+            Hint::TooLow
+        } else {
+            Hint::None
+        };
+        let wait = text.find("wait ").map(|i| minutes(&text[i + 5..]));
+        Reply::Wrong { hint, wait }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text
+            .find("You have ")
+            .and_then(|i| text[i + 9..].split(" left").next())
+            .map_or(Duration::from_secs(60), countdown);
+        Reply::Wait(wait)
+    } else if text.contains("You don't seem to be solving the right level") {
+        Reply::WrongLevel
+    } else {
+        Reply::Unknown(text)
+    }
+}
+
+/// Plain text of the main `<article>` element, or the whole page if there isn't one.
+fn article(body: &str) -> String {
+    let start = body.find("<article").unwrap_or(0);
+    let end = body[start..].find("</article>").map_or(body.len(), |end| start + end);
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in body[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses phrases such as `one minute` or `5 minutes`, defaulting to one minute.
+fn minutes(text: &str) -> Duration {
+    let minutes = match text.split_whitespace().next() {
+        Some("one") => 1,
+        Some(word) => word.parse().unwrap_or(1),
+        None => 1,
+    };
+    Duration::from_secs(60 * minutes)
+}
+
+/// Parses countdowns such as `37s` or `4m 12s`.
+fn countdown(text: &str) -> Duration {
+    let seconds = text
+        .split_whitespace()
+        .filter_map(|part| {
+            let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+            match unit {
+                "h" => Some(3600 * number),
+                "m" => Some(60 * number),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum();
+    Duration::from_secs(seconds)
+}
@@ -0,0 +1,223 @@
+//! Common contract implemented by every puzzle solution, plus the registry of all solutions.
+//!
+//! Each day is declared exactly once in the [`solutions!`] invocation in `lib.rs`. That single
+//! registration both declares the module and implements [`Solution`] for the matching
+//! [`Puzzle`] marker type, so the runner, tests and benchmarks all read the same list from
+//! [`solutions`] and can never drift apart.
+//!
+//! [`Solution`] is the typed interface, useful when the year and day are known at compile time:
+//!
+//! ```
+//! use aoc::solution::{Answer, Puzzle, Solution};
+//!
+//! let input = Puzzle::<2019, 1>::parse("14").unwrap();
+//! assert_eq!(Puzzle::<2019, 1>::part1(&input), Answer::Integer(2));
+//! ```
+//!
+//! [`Entry`] erases the input type so that solutions for different days can be stored in the
+//! same list and driven by generic tooling.
+//!
+//! Parsing is fallible so that input in the wrong format is reported as an [`AocError`] pointing
+//! at the problem, rather than a panic. The parts themselves always succeed once given a
+//! parsed input.
+use crate::error::{AocError, AocResult};
+use std::any::Any;
+use std::env;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Environment variable that overrides the default input directory.
+pub const INPUT_DIR: &str = "AOC_INPUT_DIR";
+
+/// Implemented once per day by the [`solutions!`] registration.
+pub trait Solution {
+    const YEAR: u32;
+    const DAY: u32;
+
+    /// Parsed puzzle input shared by both parts.
+    type Input: 'static;
+
+    fn parse(input: &str) -> AocResult<Self::Input>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+/// Result of solving one part of a puzzle.
+///
+/// Solutions return whatever type is most natural and rely on the [`From`] conversions to
+/// build an `Answer`, so a `u32`, `i128` or `String` can be returned directly.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// Numeric answer. The only unsigned values that don't fit are stored as [`Answer::Text`].
+    Integer(i128),
+    /// Single line answer such as a comma separated list or a password.
+    Text(String),
+    /// Several lines of ASCII art that need to be read by eye, for example letters drawn on
+    /// a display.
+    Art(String),
+    /// This part has no answer, for example the second part of the final day.
+    Empty,
+}
+
+impl Answer {
+    /// Each line of the answer, which is exactly one line except for [`Answer::Art`].
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        let text = match self {
+            Answer::Text(text) | Answer::Art(text) => Some(text.as_str()),
+            _ => None,
+        };
+        text.into_iter().flat_map(str::lines)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{n}"),
+            Answer::Text(text) | Answer::Art(text) => f.write_str(text),
+            Answer::Empty => Ok(()),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($($t:ty)*) => ($(
+        impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                match i128::try_from(n) {
+                    Ok(n) => Answer::Integer(n),
+                    Err(_) => Answer::Text(n.to_string()),
+                }
+            }
+        }
+    )*)
+}
+
+answer_from!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_owned())
+    }
+}
+
+/// Marker type identifying a puzzle by year and day, for example `Puzzle<2023, 7>`.
+pub struct Puzzle<const YEAR: u32, const DAY: u32>;
+
+/// Type erased registry entry for a single [`Solution`].
+pub struct Entry {
+    pub year: u32,
+    pub day: u32,
+    pub parse: fn(&str) -> Result<Box<dyn Any>, AocError>,
+    pub part1: fn(&dyn Any) -> Answer,
+    pub part2: fn(&dyn Any) -> Answer,
+}
+
+impl Entry {
+    pub const fn new<S: Solution>() -> Self {
+        Entry {
+            year: S::YEAR,
+            day: S::DAY,
+            parse: parse::<S>,
+            part1: part1::<S>,
+            part2: part2::<S>,
+        }
+    }
+
+    /// Conventional location of the puzzle input within [`input_dir`],
+    /// for example `input/year2023/day07.txt`.
+    pub fn path(&self) -> PathBuf {
+        self.path_in(&input_dir())
+    }
+
+    /// Location of the puzzle input within `dir`, for example `dir/year2023/day07.txt`.
+    pub fn path_in(&self, dir: &Path) -> PathBuf {
+        input_path(dir, self.year, self.day)
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<Box<dyn Any>, AocError> {
+    S::parse(input).map(|input| Box::new(input) as Box<dyn Any>)
+}
+
+fn part1<S: Solution>(input: &dyn Any) -> Answer {
+    S::part1(downcast::<S>(input))
+}
+
+fn part2<S: Solution>(input: &dyn Any) -> Answer {
+    S::part2(downcast::<S>(input))
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> &S::Input {
+    match input.downcast_ref() {
+        Some(input) => input,
+        None => panic!("Input for {} Day {:02} has unexpected type", S::YEAR, S::DAY),
+    }
+}
+
+/// Directory holding the puzzle inputs, taken from the `AOC_INPUT_DIR` environment variable
+/// if set, otherwise `input` relative to the current directory.
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR)
+        .filter(|dir| !dir.is_empty())
+        .map_or_else(|| "input".into(), PathBuf::from)
+}
+
+/// Location of the puzzle input for any day within `dir`, even if it has no solution yet.
+pub fn input_path(dir: &Path, year: u32, day: u32) -> PathBuf {
+    dir.join(format!("year{year}")).join(format!("day{day:02}.txt"))
+}
+
+/// Every registered solution, sorted by year then day.
+pub fn solutions() -> &'static [Entry] {
+    crate::SOLUTIONS
+}
+
+/// Finds the registered solution for a specific year and day.
+pub fn find(year: u32, day: u32) -> Option<&'static Entry> {
+    solutions().iter().find(|entry| entry.year == year && entry.day == day)
+}
+
+/// Solves both parts of a puzzle, for use when embedding the library in other tools.
+///
+/// The input is checked and normalized the same way as by the runner, so for example CRLF line
+/// endings are accepted. A day without a solution, rejected input or input that fails to parse
+/// is returned as an error, whereas a panic inside a part is not caught. Use
+/// [`runner::isolate`](crate::runner::isolate) for that.
+pub fn solve(year: u32, day: u32, input: &str) -> AocResult<(Answer, Answer)> {
+    let Some(entry) = find(year, day) else {
+        return Err(AocError::new(format!("No solution for {year} Day {day:02}")));
+    };
+    let checked = crate::runner::input::check(input).map_err(AocError::new)?;
+    let input = (entry.parse)(&checked.data)?;
+
+    Ok(((entry.part1)(input.as_ref()), (entry.part2)(input.as_ref())))
+}
+
+/// Year and day of every registered solution, sorted by year then day.
+pub fn available() -> impl Iterator<Item = (u32, u32)> {
+    solutions().iter().map(|entry| (entry.year, entry.day))
+}
+
+/// Extracts the number from a module name such as `year2023` or `day07` at compile time.
+pub const fn number(name: &str) -> u32 {
+    let bytes = name.as_bytes();
+    let mut n = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let digit = bytes[i].wrapping_sub(b'0');
+        if digit < 10 {
+            n = 10 * n + digit as u32;
+        }
+        i += 1;
+    }
+
+    n
+}
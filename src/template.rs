@@ -1,6 +1,13 @@
-pub fn part1(input: &str) -> u32 {
+//! # Day N: [Title]
+//!
+//! Starting point for a new day, created by `cargo run -- new yearYYYY::dayDD`.
+//! Replace the placeholder answers, then optionally export an `Input` type and a `parse`
+//! function and mark the day `: parse` in `lib.rs` to time parsing separately.
 
+pub fn part1(_input: &str) -> u32 {
+    123
 }
-pub fn part2(input: &str) -> u32 {
 
-}
\ No newline at end of file
+pub fn part2(_input: &str) -> u32 {
+    456
+}
@@ -0,0 +1,192 @@
+//! Fast 2 dimensional grid backed by a single `Vec`, indexed by [`Point`].
+//!
+//! A common pattern in Advent of Code is a rectangular map of characters such as:
+//!
+//! ```none
+//!   ..#.
+//!   #.S.
+//!   ....
+//! ```
+//!
+//! [`Grid::parse`] turns this into a `Grid<u8>` of the raw bytes, stored row by row so that the
+//! cell at `(x, y)` lives at index `y * width + x`. Points outside the grid are handled by
+//! [`Grid::contains`] and [`Grid::get`], while the neighbour iterators only ever return points
+//! that are inside the grid.
+//!
+//! Other per cell state, for example whether a cell has been visited or the cost to reach it, is
+//! kept in a second grid of the same size made with [`Grid::same_size_with`].
+use crate::error::{AocError, AocResult};
+use crate::util::point::{Point, DIAGONAL, ORTHOGONAL};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    pub width: i64,
+    pub height: i64,
+    pub bytes: Vec<T>,
+}
+
+impl Grid<u8> {
+    /// Parses a rectangle of ASCII characters, one row per line.
+    ///
+    /// # Panics
+    ///
+    /// If the lines have different lengths, see [`Grid::try_parse`].
+    pub fn parse(input: &str) -> Self {
+        Grid::try_parse(input).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Parses a rectangle of ASCII characters, pointing at the first line with a different
+    /// length to the one before.
+    pub fn try_parse(input: &str) -> AocResult<Self> {
+        let lines: Vec<_> = input.lines().collect();
+        let width = lines.first().map_or(0, |line| line.len());
+
+        if let Some(line) = lines.iter().find(|line| line.len() != width) {
+            let message = format!("Expected {width} characters, found {}", line.len());
+            return Err(AocError::within(input, line, message));
+        }
+
+        let bytes = lines.iter().flat_map(|line| line.bytes()).collect();
+        Ok(Grid { width: width as i64, height: lines.len() as i64, bytes })
+    }
+}
+
+impl<T: Copy> Grid<T> {
+    /// Grid of the given size with every cell set to `value`.
+    pub fn new(width: i64, height: i64, value: T) -> Self {
+        Grid { width, height, bytes: vec![value; (width * height) as usize] }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Grid with the same width and height as this one, with every cell set to `value`.
+    pub fn same_size_with<U: Copy>(&self, value: U) -> Grid<U> {
+        Grid::new(self.width, self.height, value)
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= 0 && point.x < self.width && point.y >= 0 && point.y < self.height
+    }
+
+    #[inline]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point).then(|| &self.bytes[self.index(point)])
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            let index = self.index(point);
+            Some(&mut self.bytes[index])
+        } else {
+            None
+        }
+    }
+
+    /// Every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width, self.height);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// First point, row by row, whose cell matches `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.bytes.iter().position(predicate).map(|index| self.point(index))
+    }
+
+    /// Every point, row by row, whose cell matches `predicate`.
+    pub fn positions<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.points().filter(move |&point| predicate(&self[point]))
+    }
+
+    /// The up to 4 orthogonal neighbours of `point` that are inside the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &ORTHOGONAL)
+    }
+
+    /// The up to 8 orthogonal and diagonal neighbours of `point` that are inside the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &DIAGONAL)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        point: Point,
+        offsets: &'static [Point],
+    ) -> impl Iterator<Item = Point> + 'a {
+        offsets
+            .iter()
+            .map(move |&offset| point + offset)
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    #[inline]
+    fn index(&self, point: Point) -> usize {
+        (self.width * point.y + point.x) as usize
+    }
+
+    #[inline]
+    fn point(&self, index: usize) -> Point {
+        let index = index as i64;
+        Point::new(index % self.width, index / self.width)
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// First point, row by row, containing `value`.
+    pub fn find(&self, value: T) -> Option<Point> {
+        self.position(|cell| *cell == value)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, point: Point) -> &Self::Output {
+        assert!(self.contains(point), "{point:?} is outside the grid");
+        &self.bytes[self.index(point)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        assert!(self.contains(point), "{point:?} is outside the grid");
+        let index = self.index(point);
+        &mut self.bytes[index]
+    }
+}
+
+/// Prints each cell as an ASCII character, one row per line.
+impl fmt::Display for Grid<u8> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, |&b| b as char)
+    }
+}
+
+/// Prints one row per line.
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, |&c| c)
+    }
+}
+
+impl<T> Grid<T> {
+    fn display(&self, f: &mut fmt::Formatter<'_>, to_char: impl Fn(&T) -> char) -> fmt::Result {
+        for (y, row) in self.bytes.chunks(self.width.max(1) as usize).enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            let row: String = row.iter().map(&to_char).collect();
+            f.write_str(&row)?;
+        }
+        Ok(())
+    }
+}
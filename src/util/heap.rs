@@ -0,0 +1,149 @@
+//! Priority queues that pop the *lowest* cost first, as needed by Dijkstra and A*.
+//!
+//! [`BinaryHeap`] is a max heap, so using it for shortest paths normally means a `State` struct
+//! with hand written `Ord` and `PartialOrd` impls that reverse the comparison. [`MinHeap`] takes
+//! the cost and the payload separately instead, so neither needs any ordering of its own beyond
+//! the cost being [`Ord`].
+//!
+//! When costs are small integers, such as the single digit weights of most grid puzzles,
+//! [`BucketQueue`] is usually faster. It keeps one bucket per cost and walks forward through
+//! them, so each push and pop is `O(1)` apart from the walk over empty buckets.
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+struct Wrapper<K: Ord, V> {
+    key: K,
+    value: V,
+}
+
+impl<K: Ord, V> PartialEq for Wrapper<K, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<K: Ord, V> Eq for Wrapper<K, V> {}
+
+impl<K: Ord, V> PartialOrd for Wrapper<K, V> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, V> Ord for Wrapper<K, V> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.key.cmp(&self.key)
+    }
+}
+
+/// Pops the entry with the smallest key first. Entries with equal keys come out in no
+/// particular order.
+pub struct MinHeap<K: Ord, V> {
+    heap: BinaryHeap<Wrapper<K, V>>,
+}
+
+impl<K: Ord, V> MinHeap<K, V> {
+    pub fn new() -> Self {
+        MinHeap { heap: BinaryHeap::new() }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        MinHeap { heap: BinaryHeap::with_capacity(capacity) }
+    }
+
+    #[inline]
+    pub fn push(&mut self, key: K, value: V) {
+        self.heap.push(Wrapper { key, value });
+    }
+
+    #[inline]
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        self.heap.pop().map(|Wrapper { key, value }| (key, value))
+    }
+
+    /// Smallest key and its value, without removing them.
+    pub fn peek(&self) -> Option<(&K, &V)> {
+        self.heap.peek().map(|Wrapper { key, value }| (key, value))
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.heap.clear();
+    }
+}
+
+impl<K: Ord, V> Default for MinHeap<K, V> {
+    fn default() -> Self {
+        MinHeap::new()
+    }
+}
+
+/// Pops the entry with the smallest integer cost first, also known as Dial's algorithm.
+///
+/// Memory grows with the largest cost pushed, so this suits costs up to a few million. Entries
+/// with equal costs come out most recently pushed first.
+pub struct BucketQueue<V> {
+    buckets: Vec<Vec<V>>,
+    /// Every bucket below this is empty.
+    current: usize,
+    len: usize,
+}
+
+impl<V> BucketQueue<V> {
+    pub fn new() -> Self {
+        BucketQueue { buckets: Vec::new(), current: 0, len: 0 }
+    }
+
+    #[inline]
+    pub fn push(&mut self, cost: usize, value: V) {
+        if cost >= self.buckets.len() {
+            self.buckets.resize_with(cost + 1, Vec::new);
+        }
+        self.buckets[cost].push(value);
+        self.current = self.current.min(cost);
+        self.len += 1;
+    }
+
+    #[inline]
+    pub fn pop(&mut self) -> Option<(usize, V)> {
+        if self.len == 0 {
+            return None;
+        }
+        while self.buckets[self.current].is_empty() {
+            self.current += 1;
+        }
+        self.len -= 1;
+        self.buckets[self.current].pop().map(|value| (self.current, value))
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Empties the queue, keeping the buckets allocated for reuse.
+    pub fn clear(&mut self) {
+        self.buckets.iter_mut().for_each(Vec::clear);
+        self.current = 0;
+        self.len = 0;
+    }
+}
+
+impl<V> Default for BucketQueue<V> {
+    fn default() -> Self {
+        BucketQueue::new()
+    }
+}
@@ -0,0 +1,140 @@
+//! Number theory helpers, generic over the types in [`integer`](crate::util::integer).
+//!
+//! Puzzles built around cycles, such as several ghosts each looping through a map at a different
+//! rate, reduce to finding when all the cycles line up. When every cycle starts at zero that's
+//! the [`lcm`](IntegerMathOps::lcm) of their lengths. When the cycles are offset it's the
+//! [Chinese remainder theorem](https://en.wikipedia.org/wiki/Chinese_remainder_theorem),
+//! solved here by [`crt`] even when the cycle lengths share a common factor.
+//!
+//! None of these check for overflow. Products of two values below the modulus must fit in `T`,
+//! so for moduli above about 3 billion use `i128` or `u128`.
+use crate::util::integer::*;
+
+pub trait IntegerMathOps<T: Integer<T>> {
+    /// Greatest common divisor, never negative.
+    fn gcd(self, b: T) -> T;
+    /// Least common multiple, with `lcm(0, b)` defined as `0`.
+    fn lcm(self, b: T) -> T;
+    /// `self` raised to the power `e`, modulo `m`.
+    fn mod_pow(self, e: T, m: T) -> T;
+}
+
+pub trait SignedMathOps<T: Signed<T>> {
+    /// Returns `(g, x, y)` where `g` is the gcd and `self * x + b * y == g`.
+    fn extended_gcd(self, b: T) -> (T, T, T);
+    /// `x` between `0` and `m - 1` where `self * x` is `1` modulo `m`, or `None` if `self` and
+    /// `m` share a factor.
+    fn mod_inverse(self, m: T) -> Option<T>;
+}
+
+impl<T: Integer<T>> IntegerMathOps<T> for T {
+    fn gcd(self, b: T) -> T {
+        let (mut a, mut b) = (self, b);
+        while b != T::ZERO {
+            (a, b) = (b, a % b);
+        }
+        if a < T::ZERO {
+            T::ZERO - a
+        } else {
+            a
+        }
+    }
+
+    fn lcm(self, b: T) -> T {
+        if self == T::ZERO || b == T::ZERO {
+            return T::ZERO;
+        }
+        let lcm = self / self.gcd(b) * b;
+        if lcm < T::ZERO {
+            T::ZERO - lcm
+        } else {
+            lcm
+        }
+    }
+
+    fn mod_pow(self, e: T, m: T) -> T {
+        let mut base = self.modulo(m);
+        let mut e = e;
+        let mut result = T::ONE % m;
+
+        while e > T::ZERO {
+            if e & T::ONE == T::ONE {
+                result = (result * base) % m;
+            }
+            base = (base * base) % m;
+            e = e >> T::ONE;
+        }
+
+        result
+    }
+}
+
+impl<T: Signed<T>> SignedMathOps<T> for T {
+    fn extended_gcd(self, b: T) -> (T, T, T) {
+        let (mut old_r, mut r) = (self, b);
+        let (mut old_s, mut s) = (T::ONE, T::ZERO);
+        let (mut old_t, mut t) = (T::ZERO, T::ONE);
+
+        while r != T::ZERO {
+            let q = old_r / r;
+            (old_r, r) = (r, old_r - q * r);
+            (old_s, s) = (s, old_s - q * s);
+            (old_t, t) = (t, old_t - q * t);
+        }
+
+        if old_r < T::ZERO {
+            (-old_r, -old_s, -old_t)
+        } else {
+            (old_r, old_s, old_t)
+        }
+    }
+
+    fn mod_inverse(self, m: T) -> Option<T> {
+        let (g, x, _) = self.modulo(m).extended_gcd(m);
+        (g == T::ONE).then(|| x.modulo(m))
+    }
+}
+
+/// Smallest non-negative `x` with `x % m == r` for every `(r, m)` pair, returned together with
+/// the lcm of the moduli as `(x, lcm)`, so that every solution is `x + k * lcm`.
+///
+/// Moduli don't need to be coprime, for example `x ≡ 2 (mod 4)` and `x ≡ 4 (mod 6)` combine to
+/// `x ≡ 10 (mod 12)`. Returns `None` when the congruences contradict each other, such as
+/// `x ≡ 1 (mod 4)` and `x ≡ 2 (mod 6)`, since one needs `x` odd and the other even.
+///
+/// # Panics
+///
+/// If any modulus is not positive.
+pub fn crt<T: Signed<T>>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    congruences.iter().try_fold((T::ZERO, T::ONE), |(r1, m1), &(r2, m2)| {
+        assert!(m2 > T::ZERO, "Modulus must be positive");
+        let (g, p, _) = m1.extended_gcd(m2);
+        let difference = r2.modulo(m2) - r1;
+
+        if difference % g != T::ZERO {
+            return None;
+        }
+
+        let step = m2 / g;
+        let k = ((difference / g).modulo(step) * p.modulo(step)) % step;
+        let lcm = m1 * step;
+        Some(((r1 + m1 * k).modulo(lcm), lcm))
+    })
+}
+
+/// Euclidean remainder, always between `0` and `m - 1` for positive `m`.
+trait Modulo<T> {
+    fn modulo(self, m: T) -> T;
+}
+
+impl<T: Integer<T>> Modulo<T> for T {
+    #[inline]
+    fn modulo(self, m: T) -> T {
+        let r = self % m;
+        if r < T::ZERO {
+            r + m
+        } else {
+            r
+        }
+    }
+}
@@ -11,8 +11,16 @@
 //! reason for the separate methods is that some Advent of Code inputs contains the `-` character
 //! as a delimeter and this would cause numbers to be incorrectly parsed as negative.
 //!
+//! [`unsigned`] and [`signed`] panic if there is no number at all, while [`try_unsigned`] and
+//! [`try_signed`] return an [`AocError`] instead, for parsers that report bad input.
+//!
 //! [`iter_unsigned`]: ParseOps::iter_unsigned
 //! [`iter_signed`]: ParseOps::iter_signed
+//! [`unsigned`]: ParseOps::unsigned
+//! [`signed`]: ParseOps::signed
+//! [`try_unsigned`]: ParseOps::try_unsigned
+//! [`try_signed`]: ParseOps::try_signed
+use crate::error::{AocError, AocResult};
 use crate::util::integer::*;
 use std::marker::PhantomData;
 use std::str::Bytes;
@@ -41,25 +49,37 @@ pub struct ParseSigned<'a, T> {
 pub trait ParseOps {
     fn unsigned<T: Unsigned<T>>(&self) -> T;
     fn signed<T: Signed<T>>(&self) -> T;
+    fn try_unsigned<T: Unsigned<T>>(&self) -> AocResult<T>;
+    fn try_signed<T: Signed<T>>(&self) -> AocResult<T>;
     fn iter_unsigned<T: Unsigned<T>>(&self) -> ParseUnsigned<'_, T>;
     fn iter_signed<T: Signed<T>>(&self) -> ParseSigned<'_, T>;
 }
 
 impl ParseOps for &str {
     fn unsigned<T: Unsigned<T>>(&self) -> T {
-        match try_unsigned(&mut self.bytes()) {
+        match next_unsigned(&mut self.bytes()) {
             Some(t) => t,
             None => panic!("Unable to parse \"{self}\""),
         }
     }
 
     fn signed<T: Signed<T>>(&self) -> T {
-        match try_signed(&mut self.bytes()) {
+        match next_signed(&mut self.bytes()) {
             Some(t) => t,
             None => panic!("Unable to parse \"{self}\""),
         }
     }
 
+    fn try_unsigned<T: Unsigned<T>>(&self) -> AocResult<T> {
+        next_unsigned(&mut self.bytes())
+            .ok_or_else(|| AocError::new(format!("Expected a number, found \"{self}\"")))
+    }
+
+    fn try_signed<T: Signed<T>>(&self) -> AocResult<T> {
+        next_signed(&mut self.bytes())
+            .ok_or_else(|| AocError::new(format!("Expected a number, found \"{self}\"")))
+    }
+
     fn iter_unsigned<T: Unsigned<T>>(&self) -> ParseUnsigned<'_, T> {
         ParseUnsigned { bytes: self.bytes(), phantom: PhantomData }
     }
@@ -78,7 +98,7 @@ impl<T: Unsigned<T>> Iterator for ParseUnsigned<'_, T> {
     }
 
     fn next(&mut self) -> Option<Self::Item> {
-        try_unsigned(&mut self.bytes)
+        next_unsigned(&mut self.bytes)
     }
 }
 
@@ -91,11 +111,11 @@ impl<T: Signed<T>> Iterator for ParseSigned<'_, T> {
     }
 
     fn next(&mut self) -> Option<Self::Item> {
-        try_signed(&mut self.bytes)
+        next_signed(&mut self.bytes)
     }
 }
 
-fn try_unsigned<T: Unsigned<T>>(bytes: &mut Bytes<'_>) -> Option<T> {
+fn next_unsigned<T: Unsigned<T>>(bytes: &mut Bytes<'_>) -> Option<T> {
     let mut n = loop {
         let byte = bytes.next()?;
         let digit = byte.to_decimal();
@@ -117,7 +137,7 @@ fn try_unsigned<T: Unsigned<T>>(bytes: &mut Bytes<'_>) -> Option<T> {
     }
 }
 
-fn try_signed<T: Signed<T>>(bytes: &mut Bytes<'_>) -> Option<T> {
+fn next_signed<T: Signed<T>>(bytes: &mut Bytes<'_>) -> Option<T> {
     let (mut n, negative) = loop {
         let byte = bytes.next()?;
         let digit = byte.to_decimal();
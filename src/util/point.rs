@@ -1,17 +1,141 @@
+//! Two dimensional point, also used as a direction or offset between points.
+//!
+//! The y axis points down, matching how puzzle input is read top to bottom, so [`UP`] is
+//! `(0, -1)` and turning [`Point::clockwise`] from [`UP`] faces [`RIGHT`].
+//!
+//! Directions are parsed from any of the usual puzzle notations, arrows `^v<>`, letters `UDLR`
+//! or compass points `NESW`, as either a byte or a `char`:
+//!
+//! ```
+//! # use aoc::util::point::*;
+//! assert_eq!(Point::try_from(b'^'), Ok(UP));
+//! assert_eq!(Point::try_from('E'), Ok(RIGHT));
+//! assert_eq!(Point::new(3, 4) + DOWN * 2, Point::new(3, 6));
+//! ```
+use crate::error::AocError;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+pub const ORIGIN: Point = Point::new(0, 0);
+pub const UP: Point = Point::new(0, -1);
+pub const DOWN: Point = Point::new(0, 1);
+pub const LEFT: Point = Point::new(-1, 0);
+pub const RIGHT: Point = Point::new(1, 0);
+pub const UP_LEFT: Point = Point::new(-1, -1);
+pub const UP_RIGHT: Point = Point::new(1, -1);
+pub const DOWN_LEFT: Point = Point::new(-1, 1);
+pub const DOWN_RIGHT: Point = Point::new(1, 1);
+
+/// Up, right, down then left, clockwise starting from up.
+pub const ORTHOGONAL: [Point; 4] = [UP, RIGHT, DOWN, LEFT];
+
+/// All 8 neighbours, clockwise starting from up.
+pub const DIAGONAL: [Point; 8] = [UP, UP_RIGHT, RIGHT, DOWN_RIGHT, DOWN, DOWN_LEFT, LEFT, UP_LEFT];
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Point {
     pub x: i64,
-    pub y: i64
+    pub y: i64,
 }
 
-impl Point
-{
-    pub fn new(x: i64, y: i64) -> Self {
+impl Point {
+    pub const fn new(x: i64, y: i64) -> Self {
         Self { x, y }
     }
 
+    /// Quarter turn to the right, for example [`UP`] becomes [`RIGHT`].
+    #[inline]
+    pub fn clockwise(self) -> Self {
+        Point::new(-self.y, self.x)
+    }
+
+    /// Quarter turn to the left, for example [`UP`] becomes [`LEFT`].
+    #[inline]
+    pub fn counter_clockwise(self) -> Self {
+        Point::new(self.y, -self.x)
+    }
+
     pub fn manhattan_distance(&self, other: &Self) -> i64 {
         (self.x - other.x).abs() + (self.y - other.y).abs()
     }
-}
\ No newline at end of file
+
+    /// Number of king moves between points, where diagonal steps count as one.
+    pub fn chebyshev_distance(&self, other: &Self) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: i64) -> Self {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/// Parses a direction from `^v<>`, `UDLR` or `NESW`.
+impl TryFrom<u8> for Point {
+    type Error = AocError;
+
+    fn try_from(b: u8) -> Result<Self, Self::Error> {
+        match b {
+            b'^' | b'U' | b'N' => Ok(UP),
+            b'v' | b'D' | b'S' => Ok(DOWN),
+            b'<' | b'L' | b'W' => Ok(LEFT),
+            b'>' | b'R' | b'E' => Ok(RIGHT),
+            _ => Err(AocError::new(format!("Expected a direction, found {:?}", b as char))),
+        }
+    }
+}
+
+/// Parses a direction from `^v<>`, `UDLR` or `NESW`.
+impl TryFrom<char> for Point {
+    type Error = AocError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        u8::try_from(c)
+            .map_err(|_| AocError::new(format!("Expected a direction, found {c:?}")))
+            .and_then(Point::try_from)
+    }
+}
@@ -0,0 +1,160 @@
+//! Three dimensional point and axis-aligned box.
+//!
+//! [`Point3`] supports the same vector arithmetic as its 2D counterpart
+//! [`Point`](crate::util::point::Point), so a position and velocity can be combined directly
+//! with `position + velocity * time`.
+//!
+//! [`Cuboid`] is a box whose edges run along the axes, such as the falling bricks of
+//! 2023 Day 22. Both corners are *inclusive*, matching how puzzles usually describe them, so
+//! `1,0,1~1,2,1` is a single column 3 cubes long with a volume of 3.
+use crate::util::point::Point;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// Square of the straight line distance, which is exact and orders points the same way.
+    pub fn euclidean_squared(&self, other: &Self) -> i64 {
+        let Point3 { x, y, z } = *self - *other;
+        x * x + y * y + z * z
+    }
+
+    /// Drops the z coordinate.
+    pub fn xy(self) -> Point {
+        Point::new(self.x, self.y)
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for Point3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl SubAssign for Point3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Point3 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: i64) -> Self {
+        Point3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/// Box between two inclusive corners, with every coordinate of `min` no greater than `max`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl Cuboid {
+    /// Box with opposite corners `a` and `b`, given in any order.
+    pub fn new(a: Point3, b: Point3) -> Self {
+        let min = Point3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z));
+        let max = Point3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z));
+        Cuboid { min, max }
+    }
+
+    /// Number of unit cubes inside the box.
+    pub fn volume(&self) -> i64 {
+        let Point3 { x, y, z } = self.max - self.min;
+        (x + 1) * (y + 1) * (z + 1)
+    }
+
+    pub fn contains(&self, point: Point3) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+
+    /// `true` if `other` lies completely inside this box.
+    pub fn encloses(&self, other: &Self) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// Box shared by both, or `None` if they don't overlap. Boxes that only touch at a face
+    /// still share the cubes along that face.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = Point3::new(
+            self.min.x.max(other.min.x),
+            self.min.y.max(other.min.y),
+            self.min.z.max(other.min.z),
+        );
+        let max = Point3::new(
+            self.max.x.min(other.max.x),
+            self.max.y.min(other.max.y),
+            self.max.z.min(other.max.z),
+        );
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z).then_some(Cuboid { min, max })
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Same box moved by `offset`.
+    pub fn translate(&self, offset: Point3) -> Self {
+        Cuboid { min: self.min + offset, max: self.max + offset }
+    }
+
+    /// Shadow of the box on the xy plane, as its inclusive `(min, max)` corners.
+    pub fn project_xy(&self) -> (Point, Point) {
+        (self.min.xy(), self.max.xy())
+    }
+
+    /// Every point inside the box, ordered by x, then y, then z.
+    pub fn points(&self) -> impl Iterator<Item = Point3> {
+        let Cuboid { min, max } = *self;
+        (min.x..=max.x).flat_map(move |x| {
+            (min.y..=max.y).flat_map(move |y| (min.z..=max.z).map(move |z| Point3::new(x, y, z)))
+        })
+    }
+}
@@ -1,12 +1,19 @@
-pub fn part1(input: &str) -> u32 {
-    input.lines().map(|line| {
-        let mass: u32 = line.parse().unwrap();
+use crate::error::AocResult;
+use crate::util::parse::ParseOps;
+
+pub type Input = Vec<u32>;
+
+pub fn try_parse(input: &str) -> AocResult<Input> {
+    input.lines().map(|line| line.try_unsigned().map_err(|e| e.locate(input, line))).collect()
+}
+
+pub fn part1(input: &[u32]) -> u32 {
+    input.iter().map(|&mass| {
         mass / 3 - 2
     }).sum()
 }
-pub fn part2(input: &str) -> u32 {
-    input.lines().map(|line| {
-        let mass: u32 = line.parse().unwrap();
+pub fn part2(input: &[u32]) -> u32 {
+    input.iter().map(|&mass| {
         let mut fuel = 0;
         let mut remaining_mass = mass;
         loop {
@@ -1,15 +1,23 @@
+use crate::error::AocResult;
 use crate::year2019::intcode::IntCode;
-pub fn part1(input: &str) -> u32 {
-    let mut intcode = IntCode::new(input.lines().next().unwrap());
+
+pub type Input = IntCode;
+
+pub fn try_parse(input: &str) -> AocResult<Input> {
+    IntCode::try_new(input)
+}
+
+pub fn part1(input: &Input) -> u32 {
+    let mut intcode = input.clone();
     intcode.memory[1] = 12;
     intcode.memory[2] = 2;
     intcode.start();
     intcode.memory[0] as u32
 }
-pub fn part2(input: &str) -> u32 {
+pub fn part2(input: &Input) -> u32 {
     for i in 0..100 {
         for j in 0..100 {
-            let mut intcode = IntCode::new(input.lines().next().unwrap());
+            let mut intcode = input.clone();
             intcode.memory[1] = i;
             intcode.memory[2] = j;
             intcode.start();
@@ -1,19 +1,24 @@
+use crate::error::AocResult;
 use crate::year2019::intcode::IntCode;
 
-pub fn part1(input: &str) -> i64 {
-    let l = input.lines().next().unwrap();
-    let mut intcode = IntCode::new(l);
+pub type Input = IntCode;
+
+pub fn try_parse(input: &str) -> AocResult<Input> {
+    IntCode::try_new(input)
+}
+
+pub fn part1(input: &Input) -> i64 {
+    let mut intcode = input.clone();
     intcode.input = 1;
     intcode.start();
     println!("{:?}", intcode.output);
     intcode.output.unwrap()
 }
 
-pub fn part2(input: &str) -> u32 {
-    let l = input.lines().next().unwrap();
-    let mut intcode = IntCode::new(l);
+pub fn part2(input: &Input) -> u32 {
+    let mut intcode = input.clone();
     intcode.input = 5;
     intcode.start();
     println!("{:?}", intcode.output);
     intcode.output.unwrap() as u32
-}
\ No newline at end of file
+}
@@ -1,5 +1,8 @@
+use crate::error::AocResult;
+use crate::util::parse::ParseOps;
 use crate::year2019::intcode::Mode::{Immediate, Position};
 
+#[derive(Clone)]
 pub struct IntCode {
     pub memory: Vec<i64>,
     ip: usize,
@@ -9,9 +12,17 @@ pub struct IntCode {
 }
 
 impl IntCode{
-    pub(crate) fn new(input: &str) -> Self {
-        let memory:Vec<_> = input.split(',').map(|x| x.parse::<i64>().unwrap()).collect();
-        IntCode { memory:memory.clone(),ip: 0, halted: false, input: 1, output:None }
+    /// Loads a comma separated program, pointing at the first value that isn't a number.
+    pub fn try_new(input: &str) -> AocResult<Self> {
+        let memory = input
+            .trim_end()
+            .split(',')
+            .map(|x| {
+                let value = x.trim_start();
+                value.try_signed::<i64>().map_err(|e| e.locate(input, value))
+            })
+            .collect::<AocResult<Vec<_>>>()?;
+        Ok(IntCode { memory, ip: 0, halted: false, input: 1, output: None })
     }
 
     pub(crate) fn start(&mut self){
@@ -1,3 +1,4 @@
+use crate::util::math::IntegerMathOps;
 use Dir::{Left, Right};
 use regex::Regex;
 use std::collections::HashMap;
@@ -73,13 +74,7 @@ pub fn part2(input: &str) -> u128 {
         }
         first_z.insert(key.clone(), count);
     }
-    let g = lcm(first_z
-        .values()
-        .map(|s| s.clone())
-        .collect::<Vec<u128>>()
-        .as_slice());
-
-    g
+    first_z.values().fold(1, |acc, &steps| acc.lcm(steps))
 }
 
 fn get_map(input: &str) -> HashMap<String, (String, String)> {
@@ -117,22 +112,6 @@ enum Dir {
     Right,
 }
 
-pub fn lcm(nums: &[u128]) -> u128 {
-    if nums.len() == 1 {
-        return nums[0];
-    }
-    let a = nums[0];
-    let b = lcm(&nums[1..]);
-    a * b / gcd_of_two_numbers(a, b)
-}
-
-fn gcd_of_two_numbers(a: u128, b: u128) -> u128 {
-    if b == 0 {
-        return a;
-    }
-    gcd_of_two_numbers(b, a % b)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
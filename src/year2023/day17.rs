@@ -1,6 +1,6 @@
+use crate::util::heap::BucketQueue;
 use ndarray::{Array2, Axis};
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap};
+use std::collections::HashMap;
 
 fn create_grid(input: &str) -> Array2<char> {
     let lines = input.lines().collect::<Vec<_>>();
@@ -24,19 +24,10 @@ pub fn part2(input: &str) -> i32 {
 fn min_distance(input: &str, min_steps: usize, max_steps: usize) -> Option<i32> {
     let grid = create_grid(input);
     let mut distances = HashMap::<(i32, i32, (i32, i32)), i32>::new();
-    let mut q = BinaryHeap::<Item>::new();
-    q.push(Item {
-        cost: 0,
-        row: 0,
-        col: 0,
-        dr: 0,
-        dc: 0,
-    });
-    while let Some(item) = q.pop() {
-        let row = item.row;
-        let col = item.col;
-        let cost = item.cost;
-        let dir = (item.dr, item.dc);
+    let mut q = BucketQueue::new();
+    q.push(0, (0, 0, (0, 0)));
+    while let Some((cost, (row, col, dir))) = q.pop() {
+        let cost = cost as i32;
 
         // Are we in the bottom right?
         if (row, col)
@@ -88,13 +79,7 @@ fn min_distance(input: &str, min_steps: usize, max_steps: usize) -> Option<i32>
                     distances.insert(key, next_cost);
 
                     // Push the current item onto the heap
-                    q.push(Item {
-                        cost: next_cost,
-                        row: rr,
-                        col: cc,
-                        dr,
-                        dc,
-                    });
+                    q.push(next_cost as usize, (rr, cc, (dr, dc)));
                 }
             }
         }
@@ -102,29 +87,6 @@ fn min_distance(input: &str, min_steps: usize, max_steps: usize) -> Option<i32>
     None
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct Item {
-    cost: i32,
-    row: i32,
-    col: i32,
-    dr: i32,
-    dc: i32,
-}
-
-// Reverse ordering so we get min-heap behavior
-impl Ord for Item {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.cost.cmp(&self.cost)
-    }
-}
-
-// What the crap is this used for
-impl PartialOrd for Item {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        other.cost.partial_cmp(&self.cost)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
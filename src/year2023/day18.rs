@@ -1,27 +1,16 @@
-use Dir::{Down, Left, Right, Up};
+use crate::util::point::{Point, DOWN, LEFT, RIGHT, UP};
 use itertools::Itertools;
 
 pub fn part1(input: &str) -> i64 {
     let mut positions = Vec::<(i64, i64)>::new();
-    let mut curr = (0, 0);
+    let mut curr = Point::new(0, 0);
     input.lines().for_each(|l| {
         let pogs = l.split_whitespace().collect::<Vec<_>>();
         let amount = pogs.get(1).unwrap().parse::<usize>().unwrap();
-        let dir = match pogs.get(0).unwrap() {
-            &"U" => Up,
-            &"D" => Down,
-            &"L" => Left,
-            &"R" => Right,
-            _ => panic!("unmapped"),
-        };
+        let dir = Point::try_from(pogs[0].as_bytes()[0]).unwrap();
         for _ in 0..amount {
-            match dir {
-                Left => curr.0 -= 1,
-                Up => curr.1 -= 1,
-                Down => curr.1 += 1,
-                Right => curr.0 += 1,
-            }
-            positions.push(curr.clone())
+            curr += dir;
+            positions.push((curr.x, curr.y))
         }
     });
     positions.reverse();
@@ -38,17 +27,9 @@ fn shoelace(positions: &Vec<(i64, i64)>) -> i64 {
     return (a - b).abs() / 2 - positions.len() as i64 / 2 + 1;
 }
 
-#[derive(Debug)]
-enum Dir {
-    Left,
-    Up,
-    Down,
-    Right,
-}
-
 pub fn part2(input: &str) -> i64 {
     let mut positions = Vec::<(i64, i64)>::new();
-    let mut curr = (0, 0);
+    let mut curr = Point::new(0, 0);
     input.lines().for_each(|l| {
         let pogs = l.split_whitespace().collect::<Vec<_>>();
         let hex_chars = pogs.get(2).unwrap().chars();
@@ -62,20 +43,15 @@ pub fn part2(input: &str) -> i64 {
         let amt_2 = i64::from_str_radix(amt.to_string().as_str(), 16).unwrap();
 
         let dir = match d_string {
-            '0' => Right,
-            '1' => Down,
-            '2' => Left,
-            '3' => Up,
+            '0' => RIGHT,
+            '1' => DOWN,
+            '2' => LEFT,
+            '3' => UP,
             _ => panic!("unmapped"),
         };
         for _ in 0..amt_2 {
-            match dir {
-                Left => curr.0 -= 1,
-                Up => curr.1 -= 1,
-                Down => curr.1 += 1,
-                Right => curr.0 += 1,
-            }
-            positions.push(curr.clone())
+            curr += dir;
+            positions.push((curr.x, curr.y))
         }
     });
     shoelace(&positions) + positions.len() as i64
@@ -1,14 +1,29 @@
+use crate::error::{AocError, AocResult};
+use crate::util::parse::ParseOps;
 use std::ops::RangeInclusive;
 
+pub struct Input {
+    rules: Vec<Rule>,
+    xmases: Vec<xmas>,
+}
+
+pub fn try_parse(input: &str) -> AocResult<Input> {
+    let Some((rules, xmases)) = input.split_once("\n\n") else {
+        let message = "Expected a blank line between the workflows and the parts";
+        return Err(AocError::at(input, input.len(), message));
+    };
+    let rules = rules.lines().map(|line| Rule::parse(input, line)).collect::<AocResult<_>>()?;
+    let xmases = xmases.lines().map(|line| xmas::parse(input, line)).collect::<AocResult<_>>()?;
+
+    Ok(Input { rules, xmases })
+}
 
-pub fn part1(input: &str) -> usize {
-    let (rules, xmases) = input.split_once("\n\n").unwrap();
-    let rules = rules.lines().map(Rule::from).collect::<Vec<_>>();
-    let xmases = xmases.lines().map(xmas::from).collect::<Vec<_>>();
+pub fn part1(input: &Input) -> usize {
+    let Input { rules, xmases } = input;
 
     let sum: u32 = xmases
         .iter()
-        .filter(|xmas| simulate(&rules, xmas))
+        .filter(|xmas| simulate(rules, xmas))
         .map(|xmas| xmas.get_score())
         .sum();
 
@@ -78,31 +93,41 @@ impl xmas {
     }
 }
 
-impl From<&str> for xmas {
-    fn from(value: &str) -> Self {
-        let value = value.trim_matches(|c| c == '{' || c == '}');
-        let mut x = None;
-        let mut m = None;
-        let mut a = None;
-        let mut s = None;
+impl xmas {
+    fn parse(input: &str, line: &str) -> AocResult<Self> {
+        let error = |at: &str, message: String| Err(AocError::within(input, at, message));
+
+        let Some(value) = line.strip_prefix('{').and_then(|line| line.strip_suffix('}')) else {
+            return error(line, format!("Expected a part in braces, found \"{line}\""));
+        };
+        let mut ratings = [None; 4];
 
         for part in value.split(',') {
-            let (key, val) = part.split_once('=').unwrap();
-            match key {
-                "x" => x = Some(val.parse().unwrap()),
-                "m" => m = Some(val.parse().unwrap()),
-                "a" => a = Some(val.parse().unwrap()),
-                "s" => s = Some(val.parse().unwrap()),
-                _ => unreachable!("Unknown key: {}", key),
-            }
+            let Some((key, val)) = part.split_once('=') else {
+                return error(part, format!("Expected a rating such as x=787, found \"{part}\""));
+            };
+            let Some(index) = category(key) else {
+                return error(key, format!("Expected one of x, m, a or s, found \"{key}\""));
+            };
+            let val = val.try_unsigned().map_err(|e| e.locate(input, val))?;
+            ratings[index] = Some(val);
         }
 
-        Self {
-            x: x.unwrap(),
-            m: m.unwrap(),
-            a: a.unwrap(),
-            s: s.unwrap(),
-        }
+        let [Some(x), Some(m), Some(a), Some(s)] = ratings else {
+            return error(line, "Expected ratings for each of x, m, a and s".to_owned());
+        };
+        Ok(Self { x, m, a, s })
+    }
+}
+
+/// Index of a rating category, in the order `xmas`.
+fn category(key: &str) -> Option<usize> {
+    match key {
+        "x" => Some(0),
+        "m" => Some(1),
+        "a" => Some(2),
+        "s" => Some(3),
+        _ => None,
     }
 }
 
@@ -113,33 +138,46 @@ struct Rule {
     rule_options: Vec<RuleOption>,
 }
 
-impl From<&str> for Rule {
-    fn from(value: &str) -> Self {
-        let (input, rest) = value.split_once("{").unwrap();
-        let options = rest.split(',').collect::<Vec<&str>>();
-        let catch_all = options
-            .last()
-            .unwrap()
-            .trim_matches(|c| c == '{' || c == '}');
-        let rules = options[..options.len() - 1]
-            .iter()
-            .map(|s| {
-                let (xmas, value) = s.split_once(&['<', '>']).unwrap();
-                let (value, result) = value.split_once(':').unwrap();
-                RuleOption {
-                    value: value.parse().unwrap(),
-                    result: result.trim_end_matches('}').to_string(),
-                    xmas: xmas.chars().next().unwrap(),
-                    greater_than: s.contains('>'),
-                }
-            })
-            .collect();
+impl Rule {
+    fn parse(input: &str, line: &str) -> AocResult<Self> {
+        let error = |at: &str, message: String| Err(AocError::within(input, at, message));
 
-        Self {
-            input: input.to_string(),
-            last: catch_all.to_string(),
-            rule_options: rules,
+        let Some((name, rest)) = line.split_once('{') else {
+            return error(line, "Expected a workflow such as px{a<2006:qkq,rfg}".to_owned());
+        };
+        let Some(rest) = rest.strip_suffix('}') else {
+            return error(&line[line.len()..], "Expected '}' at the end of the workflow".to_owned());
+        };
+        let mut options = rest.split(',').collect::<Vec<&str>>();
+        let catch_all = options.pop().unwrap_or_default();
+        if catch_all.is_empty() || catch_all.contains(':') {
+            return error(catch_all, "Expected the workflow to end with a fallback".to_owned());
         }
+
+        let mut rule_options = Vec::with_capacity(options.len());
+
+        for s in options {
+            let Some(split) = s.find(['<', '>']) else {
+                return error(s, format!("Expected a condition such as a<2006:qkq, found \"{s}\""));
+            };
+            let (xmas, value) = (&s[..split], &s[split + 1..]);
+            if category(xmas).is_none() {
+                return error(xmas, format!("Expected one of x, m, a or s, found \"{xmas}\""));
+            }
+            let Some((value, result)) = value.split_once(':') else {
+                return error(value, "Expected ':' followed by a workflow".to_owned());
+            };
+            let value = value.try_unsigned().map_err(|e| e.locate(input, value))?;
+
+            rule_options.push(RuleOption {
+                value,
+                result: result.to_string(),
+                xmas: xmas.chars().next().unwrap_or_default(),
+                greater_than: s.as_bytes()[split] == b'>',
+            });
+        }
+
+        Ok(Self { input: name.to_string(), last: catch_all.to_string(), rule_options })
     }
 }
 
@@ -151,13 +189,10 @@ struct RuleOption {
     greater_than: bool,
 }
 
-pub fn part2(input: &str) -> u128 {
-    let (rules, _) = input.split_once("\n\n").unwrap();
-    let rules = rules.lines().map(Rule::from).collect::<Vec<_>>();
-
+pub fn part2(input: &Input) -> u128 {
     simulate_two(
         "in".to_string(),
-        &rules,
+        &input.rules,
         xmas_range {
             x: 1..=4000,
             m: 1..=4000,
@@ -260,7 +295,8 @@ mod tests {
 
     #[test]
     fn test_xmas() {
-        let xmas = xmas::from("{x=820,m=149,a=558,s=29}");
+        let input = "{x=820,m=149,a=558,s=29}";
+        let xmas = xmas::parse(input, input).unwrap();
         assert_eq!(xmas.x, 820);
         assert_eq!(xmas.m, 149);
         assert_eq!(xmas.a, 558);
@@ -29,22 +29,6 @@ pub fn part2(input: &str) -> u64 {
     ans.iter().product()
 }
 
-pub fn lcm(nums: &[usize]) -> usize {
-    if nums.len() == 1 {
-        return nums[0];
-    }
-    let a = nums[0];
-    let b = lcm(&nums[1..]);
-    a * b / gcd_of_two_numbers(a, b)
-}
-
-fn gcd_of_two_numbers(a: usize, b: usize) -> usize {
-    if b == 0 {
-        return a;
-    }
-    gcd_of_two_numbers(b, a % b)
-}
-
 struct System {
     modules: Vec<Module>,
     high_pulses_sent: u32,
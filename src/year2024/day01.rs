@@ -3,8 +3,10 @@
 use std::collections::HashMap;
 
 
-pub fn part1(input: &str) -> usize {
+/// Left and right location lists.
+pub type Input = (Vec<i32>, Vec<i32>);
 
+pub fn parse(input: &str) -> Input {
     let lines = input.lines().collect::<Vec<_>>();
     let mut lefts = vec![];
     let mut rights = vec![];
@@ -14,6 +16,11 @@ pub fn part1(input: &str) -> usize {
         rights.push(ans.next().unwrap().parse::<i32>().unwrap());
         assert!(ans.next().is_none())
     });
+    (lefts, rights)
+}
+
+pub fn part1(input: &Input) -> usize {
+    let (mut lefts, mut rights) = input.clone();
 
     lefts.sort();
     rights.sort();
@@ -26,16 +33,8 @@ pub fn part1(input: &str) -> usize {
     diffs as usize
 }
 
-pub fn part2(input: &str) -> u32 {
-    let lines = input.lines().collect::<Vec<_>>();
-    let mut lefts = vec![];
-    let mut rights = vec![];
-    lines.iter().for_each(|l| {
-        let mut ans = l.split_whitespace();
-        lefts.push(ans.next().unwrap().parse::<i32>().unwrap());
-        rights.push(ans.next().unwrap().parse::<i32>().unwrap());
-        assert!(ans.next().is_none())
-    });
+pub fn part2(input: &Input) -> u32 {
+    let (lefts, rights) = input;
 
     let mut num_found = HashMap::new();
     rights.iter().for_each(|item| {
@@ -1,17 +1,20 @@
 
-pub fn part1(input: &str) -> i128 {
-    let mut output = parse_input(input);
+/// Disk map expanded to one entry per block, holding the file id or `-1` for free space.
+pub type Input = Vec<i128>;
+
+pub fn part1(input: &[i128]) -> i128 {
+    let mut output = input.to_vec();
     process_output_part1(&mut output);
     calculate_sum(&output)
 }
 
-pub fn part2(input: &str) -> i128 {
-    let mut output = parse_input(input);
+pub fn part2(input: &[i128]) -> i128 {
+    let mut output = input.to_vec();
     process_output_part2(&mut output);
     calculate_sum(&output)
 }
 
-fn parse_input(input: &str) -> Vec<i128> {
+pub fn parse(input: &str) -> Input {
     let mut output = Vec::new();
     let mut i = 0;
     for c in input.lines().next().unwrap().chars().map(|c| c.to_digit(10).unwrap()) {
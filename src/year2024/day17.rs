@@ -1,14 +1,12 @@
 use std::collections::HashMap;
 
 
-pub fn part1(input: &str) -> u32
+pub fn part1(input: &str) -> String
 {
     let mut program = Program::from_str(input);
     let result = program.run();
 
-    let result = result.iter().map(|i| i.to_string()).collect::<Vec<String>>().join(",");
-    println!("Result: {}", result);
-    0
+    result.iter().map(|i| i.to_string()).collect::<Vec<String>>().join(",")
 }
 
 pub fn part2(input: &str) -> i128 {
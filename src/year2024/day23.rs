@@ -1,7 +1,8 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
-pub fn part1(input: &str) -> i64 {
-    let graph = parse(input);
+pub type Input = Graph;
+
+pub fn part1(graph: &Input) -> i64 {
     let mut cycles = HashSet::new();
 
     for node in graph.nodes.values() {
@@ -40,14 +41,13 @@ pub fn part1(input: &str) -> i64 {
     name_starts_with_t as i64
 }
 
-pub fn part2(input: &str) -> String {
-    let graph = parse(input);
+pub fn part2(graph: &Input) -> String {
     let mut maximal_clique = Vec::new();
     let mut current_clique = HashSet::new();
     let mut potential_clique: HashSet<String> = graph.nodes.keys().cloned().collect();
     let mut excluded_nodes = HashSet::new();
 
-    bron_kerbosch(&graph, &mut current_clique, &mut potential_clique, &mut excluded_nodes, &mut maximal_clique);
+    bron_kerbosch(graph, &mut current_clique, &mut potential_clique, &mut excluded_nodes, &mut maximal_clique);
 
     maximal_clique.sort();
     maximal_clique.join(",")
@@ -76,7 +76,7 @@ fn bron_kerbosch(graph: &Graph, current_clique: &mut HashSet<String>, potential_
         excluded_nodes.insert(node);
     }
 }
-fn parse(input: &str) -> Graph {
+pub fn parse(input: &str) -> Input {
     let mut graph = Graph { nodes: HashMap::new() };
     for line in input.lines() {
         if let Some((left, right)) = line.split_once("-") {
@@ -87,7 +87,7 @@ fn parse(input: &str) -> Graph {
     graph
 }
 
-struct Graph {
+pub struct Graph {
     nodes: HashMap<String, Node>,
 }
 impl Graph {
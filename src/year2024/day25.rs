@@ -1,29 +1,20 @@
 use std::cmp::PartialEq;
+use crate::solution::Answer;
+use crate::util::grid::Grid;
+use crate::util::point::Point;
 use crate::year2024::day25::SchematicType::{Key, Lock};
 
 pub fn part1(input: &str) -> u32 {
     let grid:Vec<Schematic> = input
         .split("\n\n")
-        .map(|group| {
-            let schematic = Schematic {
-                grid: group
-                    .lines()
-                    .map(|line| line.chars().collect())
-                    .collect::<Vec<Vec<char>>>(),
-            };
-            schematic
-        }).collect();
+        .map(|group| Schematic { grid: Grid::parse(group) })
+        .collect();
     let mut count = 0;
-    let needed = grid[0].grid.len() - 2;
-    println!("{:?}", needed);
+    let needed = grid[0].grid.height - 2;
     grid.iter().filter(|x|x.get_type() == Lock).for_each(|schematic| {
-        println!("{:?}", schematic);
-        println!("{:?}", schematic.get_type());
         let total = schematic.total_per_column();
         grid.iter().filter(|x|x.get_type()== Key).for_each(|schematic2| {
             let total2 = schematic2.total_per_column();
-            println!("{:?}", total);
-            println!("{:?}", total2);
             if total.iter().zip(total2.iter()).all(|(a, b)| a + b <= needed as u32) {
                 count += 1;
             }
@@ -31,33 +22,31 @@ pub fn part1(input: &str) -> u32 {
     });
     count
 }
-pub fn part2(_input: &str) -> u32 {
-    return 0;
+/// There is no second puzzle on the final day.
+pub fn part2(_input: &str) -> Answer {
+    Answer::Empty
 }
 
 #[derive(Debug)]
 struct Schematic{
-    grid: Vec<Vec<char>>,
+    grid: Grid<u8>,
 }
 
 impl Schematic {
     fn get_type(&self)-> SchematicType{
         // if row 0 is all # its a lock
-        if self.grid[0].iter().all(|&c| c == '#'){
+        let mut top = (0..self.grid.width).map(|x| self.grid[Point::new(x, 0)]);
+        if top.clone().all(|c| c == b'#'){
             return SchematicType::Lock;
-        }else if self.grid[0].iter().all(|&c| c == '.'){
+        }else if top.all(|c| c == b'.'){
             return SchematicType::Key;
         }
         panic!("Invalid Schematic");
     }
     fn total_per_column(&self)-> Vec<u32>{
-        let mut total = vec![0; self.grid[0].len()];
-        for row in &self.grid{
-            for (i, &c) in row.iter().enumerate(){
-                if c == '#'{
-                    total[i] += 1;
-                }
-            }
+        let mut total = vec![0; self.grid.width as usize];
+        for point in self.grid.positions(|&c| c == b'#'){
+            total[point.x as usize] += 1;
         }
         // remove the all # border (which exists on key and lock)
         total.iter_mut().for_each(|x| *x -= 1);
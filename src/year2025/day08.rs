@@ -1,30 +1,14 @@
 //! # Day 8: Playground
 
+use crate::util::point3::Point3;
 use std::collections::HashMap;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Point {
-    x: i64,
-    y: i64,
-    z: i64,
-}
-
-impl Point {
-    fn distance_squared(&self, other: &Point) -> i64 {
-        (self.x - other.x).pow(2) + (self.y - other.y).pow(2) + (self.z - other.z).pow(2)
-    }
-}
-
-fn parse_input(input: &str) -> Vec<Point> {
+fn parse_input(input: &str) -> Vec<Point3> {
     input
         .lines()
         .map(|line| {
             let parts: Vec<i64> = line.split(',').map(|s| s.parse().unwrap()).collect();
-            Point {
-                x: parts[0],
-                y: parts[1],
-                z: parts[2],
-            }
+            Point3::new(parts[0], parts[1], parts[2])
         })
         .collect()
 }
@@ -73,12 +57,12 @@ impl Components {
 }
 
 /// Creates and sorts all possible edges between junction boxes by distance
-fn get_sorted_edges(points: &[Point]) -> Vec<(i64, usize, usize)> {
+fn get_sorted_edges(points: &[Point3]) -> Vec<(i64, usize, usize)> {
     let mut edges = Vec::new();
 
     for i in 0..points.len() {
         for j in (i + 1)..points.len() {
-            let distance = points[i].distance_squared(&points[j]);
+            let distance = points[i].euclidean_squared(&points[j]);
             edges.push((distance, i, j));
         }
     }
@@ -3,7 +3,7 @@
 use std::collections::HashSet;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Point {
+pub struct Point {
     x: i64,
     y: i64,
 }
@@ -51,7 +51,9 @@ impl Rectangle {
     }
 }
 
-fn parse_input(input: &str) -> Vec<Point> {
+pub type Input = Vec<Point>;
+
+pub fn parse(input: &str) -> Input {
     input
         .lines()
         .map(|line| {
@@ -73,9 +75,8 @@ fn generate_all_rectangles(points: &[Point]) -> Vec<Rectangle> {
     rectangles
 }
 
-pub fn part1(input: &str) -> usize {
-    let points = parse_input(input);
-    let rectangles = generate_all_rectangles(&points);
+pub fn part1(points: &[Point]) -> usize {
+    let rectangles = generate_all_rectangles(points);
     rectangles[0].area()
 }
 
@@ -163,14 +164,13 @@ fn rectangle_fits_in_polygon(rect: &Rectangle, polygon: &[Point], boundary: &Has
     rect.corners().iter().all(|&corner| point_inside_polygon(corner, polygon, boundary))
 }
 
-pub fn part2(input: &str) -> usize {
-    let points = parse_input(input);
-    let boundary = build_polygon_boundary(&points);
-    let rectangles = generate_all_rectangles(&points);
+pub fn part2(points: &[Point]) -> usize {
+    let boundary = build_polygon_boundary(points);
+    let rectangles = generate_all_rectangles(points);
 
     rectangles
         .iter()
-        .find(|rect| rectangle_fits_in_polygon(rect, &points, &boundary))
+        .find(|rect| rectangle_fits_in_polygon(rect, points, &boundary))
         .map(|rect| rect.area())
         .unwrap_or(0)
 }
@@ -190,36 +190,36 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(EXAMPLE), 50);
+        assert_eq!(part1(&parse(EXAMPLE)), 50);
     }
 
     #[test]
     fn test_part1_simple_square() {
         let input = "0,0\n10,0\n10,10\n0,10";
-        assert_eq!(part1(input), 121); // 11x11 grid
+        assert_eq!(part1(&parse(input)), 121); // 11x11 grid
     }
 
     #[test]
     fn test_part1_rectangle() {
         let input = "0,0\n20,0\n20,5\n0,5";
-        assert_eq!(part1(input), 126); // 21x6 grid
+        assert_eq!(part1(&parse(input)), 126); // 21x6 grid
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(EXAMPLE), 24);
+        assert_eq!(part2(&parse(EXAMPLE)), 24);
     }
 
     #[test]
     fn test_part2_simple_square() {
         let input = "0,0\n10,0\n10,10\n0,10";
-        assert_eq!(part2(input), 121); // Entire square fits
+        assert_eq!(part2(&parse(input)), 121); // Entire square fits
     }
 
     #[test]
     fn test_part2_with_indent() {
         // L-shaped polygon - largest interior rectangle is 11x6 in the bottom section
         let input = "0,0\n10,0\n10,5\n5,5\n5,10\n0,10";
-        assert_eq!(part2(input), 66); // 11 x 6 from (0,0) to (10,5)
+        assert_eq!(part2(&parse(input)), 66); // 11 x 6 from (0,0) to (10,5)
     }
 }
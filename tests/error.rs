@@ -0,0 +1,48 @@
+use aoc::error::*;
+
+#[test]
+fn at_test() {
+    let input = "1,2,3\n4,x,6\n";
+    let error = AocError::at(input, 8, "Expected a number");
+    assert_eq!((error.line, error.column), (Some(2), Some(3)));
+    assert_eq!(error.context.as_deref(), Some("4,x,6"));
+    assert_eq!(error.to_string(), "line 2, column 3: Expected a number\n  |\n2 | 4,x,6\n  |   ^");
+}
+
+#[test]
+fn within_test() {
+    let input = "first\nsecond line\n";
+    let fragment = &input[13..];
+    let error = AocError::within(input, fragment, "Unexpected");
+    assert_eq!((error.line, error.column), (Some(2), Some(8)));
+
+    // A string that isn't part of the input has no location.
+    let error = AocError::within(input, "line", "Unexpected");
+    assert_eq!(error.line, None);
+    assert_eq!(error.to_string(), "Unexpected");
+}
+
+#[test]
+fn locate_test() {
+    let input = "a\nb\nc";
+    let error = AocError::new("Bad").locate(input, &input[4..]);
+    assert_eq!((error.line, error.column), (Some(3), Some(1)));
+
+    // An existing location is kept.
+    let error = error.locate(input, input);
+    assert_eq!(error.line, Some(3));
+}
+
+#[test]
+fn end_of_input_test() {
+    let input = "ab";
+    let error = AocError::at(input, 10, "Expected more");
+    assert_eq!((error.line, error.column), (Some(1), Some(3)));
+}
+
+#[test]
+fn multibyte_test() {
+    let input = "é,x";
+    let error = AocError::at(input, 3, "Expected a number");
+    assert_eq!(error.column, Some(3));
+}
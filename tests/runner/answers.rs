@@ -0,0 +1,101 @@
+use aoc::runner::answers::*;
+use aoc::runner::record::*;
+use aoc::runner::stats::Sampling;
+use aoc::solution::{find, Answer};
+use std::path::{Path, PathBuf};
+
+#[test]
+fn parse_test() {
+    let expected = Expected::parse("part1: 1320\nnote: from the example\npart2:  co,de,ka,ta \n");
+    assert_eq!(expected.part1.as_deref(), Some("1320"));
+    assert_eq!(expected.part2.as_deref(), Some("co,de,ka,ta"));
+
+    let expected = Expected::parse("part1:\n");
+    assert_eq!(expected, Expected::default());
+}
+
+#[test]
+fn path_test() {
+    let entry = find(2023, 7).unwrap();
+    assert_eq!(path(Path::new("input"), entry), PathBuf::from("answers/year2023/day07.txt"));
+    assert_eq!(
+        path(Path::new("../private/input"), entry),
+        PathBuf::from("../private/answers/year2023/day07.txt")
+    );
+}
+
+#[test]
+fn verdict_test() {
+    let answer = Answer::Integer(42);
+    assert_eq!(Verdict::new(Some(&answer), Some("42")), Verdict::Correct);
+    assert_eq!(Verdict::new(Some(&answer), Some("41")), Verdict::Wrong("41".to_owned()));
+    assert_eq!(Verdict::new(Some(&answer), None), Verdict::Unknown);
+    assert_eq!(Verdict::new(None, Some("42")), Verdict::Wrong("42".to_owned()));
+
+    let art = Answer::Art("#.#\n.#.".to_owned());
+    assert_eq!(Verdict::new(Some(&art), Some("X")), Verdict::Unknown);
+}
+
+#[test]
+fn verify_record_test() {
+    let entry = find(2019, 1).unwrap();
+    let expected = Expected { part1: Some("33583".to_owned()), part2: Some("1".to_owned()) };
+
+    let mut record = solve(entry, PathBuf::from("example"), "100756", Sampling::default());
+    record.verify(&expected);
+    assert_eq!(record.part1_verdict, Some(Verdict::Correct));
+    assert_eq!(record.part2_verdict, Some(Verdict::Wrong("1".to_owned())));
+    assert!(record.is_wrong());
+
+    let mut record = solve(entry, PathBuf::from("example"), "100756", Sampling::default());
+    record.verify(&Expected::default());
+    assert!(!record.is_wrong());
+}
+
+#[test]
+fn submissions_test() {
+    let submissions = Submissions::parse(
+        "part1: 5\npart1_too_high: 100\npart1_too_high: 90\npart1_too_low: 10\n\
+         part1_wrong: 50\npart2_wrong: abc\nwait_until: 1000\n",
+    );
+    assert_eq!(submissions.wrong, [vec!["100", "90", "10", "50"], vec!["abc"]]);
+    assert_eq!(submissions.too_high, [Some(90), None]);
+    assert_eq!(submissions.too_low, [Some(10), None]);
+    assert_eq!(submissions.wait_until, Some(1000));
+
+    assert_eq!(
+        submissions.check(1, "20", 999),
+        Err("Too soon to submit again, wait another 1s".to_owned())
+    );
+    assert_eq!(submissions.check(1, "20", 1000), Ok(()));
+    assert_eq!(
+        submissions.check(1, "50", 1000),
+        Err("50 was already rejected for part 1".to_owned())
+    );
+    assert_eq!(
+        submissions.check(1, "95", 1000),
+        Err("95 must be too high, as 90 already was".to_owned())
+    );
+    assert_eq!(
+        submissions.check(1, "9", 1000),
+        Err("9 must be too low, as 10 already was".to_owned())
+    );
+    assert_eq!(
+        submissions.check(2, "abc", 1000),
+        Err("abc was already rejected for part 2".to_owned())
+    );
+    assert_eq!(submissions.check(2, "95", 1000), Ok(()));
+}
+
+#[test]
+fn append_test() {
+    let path = std::env::temp_dir().join(format!("aoc-append-test-{}", std::process::id()));
+    let file = path.join("day01.txt");
+
+    append(&file, "part1", "12").unwrap();
+    std::fs::write(&file, "part1: 12\nnote: no newline").unwrap();
+    append(&file, "part2", "34").unwrap();
+    assert_eq!(read(&file), "part1: 12\nnote: no newline\npart2: 34\n");
+
+    std::fs::remove_dir_all(path).unwrap();
+}
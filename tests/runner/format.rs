@@ -0,0 +1,209 @@
+use aoc::runner::answers::Verdict;
+use aoc::runner::format::*;
+use aoc::runner::memory::Usage;
+use aoc::runner::record::*;
+use aoc::runner::stats::Stats;
+use aoc::solution::Answer;
+use std::path::PathBuf;
+use std::time::Duration;
+
+fn stats(nanos: u64) -> Stats {
+    let time = Duration::from_nanos(nanos);
+    Stats { samples: 1, min: time, median: time, mean: time, p95: time }
+}
+
+fn record() -> Record {
+    Record {
+        year: 2024,
+        day: 17,
+        path: PathBuf::from("input/year2024/day17.txt"),
+        hash: Some("0123456789abcdef0123456789abcdef".to_owned()),
+        status: Status::Ok,
+        part1: Some(Answer::Text("4,6,3".to_owned())),
+        part2: Some(Answer::Empty),
+        parse_time: stats(300),
+        part1_time: stats(1500),
+        part2_time: stats(20),
+        parse_memory: None,
+        part1_memory: None,
+        part2_memory: None,
+        part1_verdict: None,
+        part2_verdict: None,
+        error: None,
+        notes: Vec::new(),
+    }
+}
+
+#[test]
+fn format_from_str_test() {
+    assert_eq!("json".parse(), Ok(Format::Json));
+    assert_eq!("csv".parse(), Ok(Format::Csv));
+    assert_eq!("text".parse(), Ok(Format::Text));
+    assert!("xml".parse::<Format>().is_err());
+}
+
+#[test]
+fn json_test() {
+    assert_eq!(
+        json(&record()),
+        "{\"year\":2024,\"day\":17,\"status\":\"ok\",\"part1\":\"4,6,3\",\"part2\":null,\
+         \"iterations\":1,\"parse_ns\":300,\"parse_min_ns\":300,\"parse_mean_ns\":300,\
+         \"parse_p95_ns\":300,\"part1_ns\":1500,\"part1_min_ns\":1500,\"part1_mean_ns\":1500,\
+         \"part1_p95_ns\":1500,\"part2_ns\":20,\"part2_min_ns\":20,\"part2_mean_ns\":20,\
+         \"part2_p95_ns\":20,\"input\":\"input/year2024/day17.txt\",\
+         \"input_md5\":\"0123456789abcdef0123456789abcdef\",\"part1_verdict\":null,\
+         \"part2_verdict\":null,\"part1_expected\":null,\"part2_expected\":null,\
+         \"error\":null,\"input_notes\":null,\"parse_allocations\":null,\
+         \"parse_allocated_bytes\":null,\"parse_peak_bytes\":null,\"part1_allocations\":null,\
+         \"part1_allocated_bytes\":null,\"part1_peak_bytes\":null,\"part2_allocations\":null,\
+         \"part2_allocated_bytes\":null,\"part2_peak_bytes\":null}"
+    );
+}
+
+#[test]
+fn json_answer_test() {
+    let mut record = record();
+
+    record.part1 = Some(Answer::Integer(-12));
+    record.part2 = Some(Answer::Art("#.\n.#".to_owned()));
+    assert!(json(&record).contains("\"part1\":-12,\"part2\":\"#.\\n.#\","));
+
+    record.part1 = None;
+    assert!(json(&record).contains("\"part1\":null,"));
+    assert_eq!(json_string("say \"hi\"\\\t\u{1}"), "\"say \\\"hi\\\"\\\\\\t\\u0001\"");
+}
+
+#[test]
+fn json_verdict_test() {
+    let mut record = record();
+    record.part1_verdict = Some(Verdict::Correct);
+    record.part2_verdict = Some(Verdict::Wrong("5".to_owned()));
+
+    let json = json(&record);
+    assert!(json.contains(
+        "\"part1_verdict\":\"correct\",\"part2_verdict\":\"wrong\",\
+         \"part1_expected\":null,\"part2_expected\":\"5\",\"error\":null,\
+         \"input_notes\":null,"
+    ));
+}
+
+#[test]
+fn csv_test() {
+    let mut record = record();
+    assert_eq!(values(&record).len(), FIELDS.len());
+    assert_eq!(
+        csv(&record),
+        "2024,17,ok,\"4,6,3\",,1,300,300,300,300,1500,1500,1500,1500,20,20,20,20,\
+         input/year2024/day17.txt,0123456789abcdef0123456789abcdef,,,,,,,,,,,,,,,"
+    );
+
+    record.status = Status::MissingInput;
+    record.hash = None;
+    record.part1 = None;
+    assert!(csv(&record).starts_with("2024,17,missing_input,,,"));
+    record.part2_verdict = Some(Verdict::Wrong("7".to_owned()));
+    assert!(csv(&record).ends_with(".txt,,,wrong,,7,,,,,,,,,,,"));
+    assert_eq!(csv_field("a \"b\""), "\"a \"\"b\"\"\"");
+}
+
+#[test]
+fn error_test() {
+    let mut record = record();
+    record.status = Status::Timeout;
+    record.error = Some("No answer after 1s".to_owned());
+
+    assert!(json(&record).contains("\"status\":\"timeout\","));
+    assert!(json(&record).contains(",\"error\":\"No answer after 1s\","));
+
+    record.status = Status::Ok;
+    record.error = None;
+    record.notes = vec!["Removed byte order mark".to_owned(), "Added newline".to_owned()];
+    assert!(csv(&record).ends_with(",,Removed byte order mark; Added newline,,,,,,,,,"));
+}
+
+#[test]
+fn not_measured_test() {
+    let entry = aoc::solution::find(2024, 17).unwrap();
+    let record =
+        Record::new(entry, PathBuf::from("input/year2024/day17.txt"), Status::MissingInput);
+
+    // Phases that never ran have no timings at all, rather than taking 0 ns.
+    assert!(json(&record).contains(
+        "\"status\":\"missing_input\",\"part1\":null,\"part2\":null,\"iterations\":null,\
+         \"parse_ns\":null,\"parse_min_ns\":null,\"parse_mean_ns\":null,\"parse_p95_ns\":null,\
+         \"part1_ns\":null,\"part1_min_ns\":null,\"part1_mean_ns\":null,\"part1_p95_ns\":null,\
+         \"part2_ns\":null,\"part2_min_ns\":null,\"part2_mean_ns\":null,\"part2_p95_ns\":null,"
+    ));
+    assert!(csv(&record).starts_with("2024,17,missing_input,,,,,,,,,,,,,,,,input/"));
+
+    // A panic in part 2 still reports the timings of the phases that finished.
+    let mut record = record;
+    record.status = Status::Panicked;
+    record.parse_time = stats(300);
+    record.part1_time = stats(1500);
+    assert!(json(&record).contains("\"iterations\":1,\"parse_ns\":300,"));
+    assert!(json(&record).contains("\"part1_p95_ns\":1500,\"part2_ns\":null,"));
+}
+
+#[test]
+fn memory_test() {
+    let mut record = record();
+    record.part1_memory = Some(Usage { allocations: 3, bytes: 4096, peak: 1024 });
+
+    assert!(json(&record).ends_with(
+        "\"parse_peak_bytes\":null,\"part1_allocations\":3,\"part1_allocated_bytes\":4096,\
+         \"part1_peak_bytes\":1024,\"part2_allocations\":null,\"part2_allocated_bytes\":null,\
+         \"part2_peak_bytes\":null}"
+    ));
+    assert!(csv(&record).ends_with(",,,,3,4096,1024,,,"));
+}
+
+#[test]
+fn bytes_test() {
+    assert_eq!(bytes(1023), "1023 B");
+    assert_eq!(bytes(1536), "1.5 KiB");
+    assert_eq!(bytes(5 * 1024 * 1024), "5.0 MiB");
+}
+
+#[test]
+fn duration_test() {
+    assert_eq!(duration(Duration::from_micros(999)), "999 µs");
+    assert_eq!(duration(Duration::from_micros(12_345)), "12.3 ms");
+}
+
+#[test]
+fn machine_output_with_printing_solutions_test() {
+    // Both days print debugging output while solving.
+    let dir = std::env::temp_dir().join(format!("aoc-format-test-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("year2019")).unwrap();
+    std::fs::write(dir.join("year2019/day05.txt"), "3,0,4,0,99\n").unwrap();
+    std::fs::write(
+        dir.join("year2019/day06.txt"),
+        "COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L\nK)YOU\nI)SAN\n",
+    )
+    .unwrap();
+
+    let run = |format: &str| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["--format", format, "--input-dir", dir.to_str().unwrap()])
+            .args(["year2019::day05", "year2019::day06"])
+            .output()
+            .unwrap()
+    };
+
+    let output = run("json");
+    let records: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let records = records.as_array().unwrap();
+    assert_eq!(records.len(), 2);
+    assert_eq!((&records[0]["year"], &records[0]["day"]), (&2019.into(), &5.into()));
+    assert_eq!(records[1]["status"], "ok");
+    assert_eq!((&records[1]["part1"], &records[1]["part2"]), (&54.into(), &4.into()));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Opcode"));
+
+    let output = run("csv");
+    let csv = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(csv.lines().count(), 3);
+    assert!(csv.lines().all(|line| line.starts_with("year,") || line.starts_with("2019,")));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
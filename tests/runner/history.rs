@@ -0,0 +1,94 @@
+use aoc::runner::history::*;
+use aoc::runner::record::{solve, Record, Status};
+use aoc::runner::stats::Sampling;
+use aoc::solution::find;
+use std::path::PathBuf;
+use std::time::Duration;
+
+fn timing(revision: &str, machine: &str, day: u32, total_ns: u64) -> Timing {
+    Timing {
+        revision: revision.to_owned(),
+        machine: machine.to_owned(),
+        timestamp: 1700000000,
+        year: 2024,
+        day,
+        parse: Duration::ZERO,
+        part1: Duration::from_nanos(total_ns),
+        part2: Duration::ZERO,
+    }
+}
+
+#[test]
+fn new_test() {
+    let entry = find(2019, 1).unwrap();
+    let record = solve(entry, PathBuf::from("example"), "100756", Sampling::default());
+    let timing = Timing::new(&record, "abc1234", "box", 42).unwrap();
+    assert_eq!((timing.year, timing.day, timing.timestamp), (2019, 1, 42));
+    assert_eq!(timing.total(), record.total_time());
+
+    let record = Record::new(entry, PathBuf::from("example"), Status::Panicked);
+    assert_eq!(Timing::new(&record, "abc1234", "box", 42), None);
+}
+
+#[test]
+fn append_and_load_test() {
+    let dir = std::env::temp_dir().join(format!("aoc-history-test-{}", std::process::id()));
+    let path = dir.join("history.csv");
+
+    append(&path, &[timing("aaa", "box", 1, 100)]).unwrap();
+    append(&path, &[timing("bbb", "box", 1, 200), timing("bbb", "box", 2, 300)]).unwrap();
+
+    let text = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(text.lines().next(), Some(HEADER));
+    assert_eq!(text.lines().nth(1), Some("aaa,box,1700000000,2024,1,0,100,0"));
+    assert_eq!(text.lines().count(), 4);
+
+    let timings = load(&path).unwrap();
+    assert_eq!(
+        timings,
+        [timing("aaa", "box", 1, 100), timing("bbb", "box", 1, 200), timing("bbb", "box", 2, 300)]
+    );
+
+    std::fs::write(&path, format!("{HEADER}\nbbb,box,oops\n")).unwrap();
+    assert!(load(&path).unwrap_err().ends_with("line 2: unexpected \"bbb,box,oops\""));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn sanitize_test() {
+    assert_eq!(sanitize(" laptop, M2\n"), "laptop_ M2");
+    assert_eq!(sanitize("a\r\nb"), "a__b");
+
+    // A sanitized name always survives a round trip through the history file.
+    let dir = std::env::temp_dir().join(format!("aoc-sanitize-test-{}", std::process::id()));
+    let path = dir.join("history.csv");
+    append(&path, &[timing("aaa", &sanitize("desk,top\n"), 1, 100)]).unwrap();
+    assert_eq!(load(&path).unwrap(), [timing("aaa", "desk_top", 1, 100)]);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn compare_test() {
+    let timings = [
+        timing("aaa", "box", 1, 100),
+        timing("aaa", "box", 2, 100),
+        timing("aaa", "other", 1, 1),
+        timing("bbb", "box", 1, 150),
+        timing("bbb", "box", 1, 120),
+        timing("bbb", "box", 2, 80),
+        timing("bbb", "box", 3, 80),
+    ];
+
+    assert_eq!(revisions(&timings, "box"), ["aaa", "bbb"]);
+    assert_eq!(revisions(&timings, "other"), ["aaa"]);
+
+    let changes = compare(&timings, "box", "aaa", "bbb");
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].current, Duration::from_nanos(120));
+    assert!((changes[0].percent() - 20.0).abs() < 1e-9);
+    assert!((changes[1].percent() + 20.0).abs() < 1e-9);
+
+    assert!(compare(&timings, "other", "aaa", "bbb").is_empty());
+}
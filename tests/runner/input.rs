@@ -0,0 +1,39 @@
+use aoc::runner::input::*;
+use std::borrow::Cow;
+
+#[test]
+fn clean_test() {
+    let checked = check("1\n2\n").unwrap();
+    assert!(matches!(checked.data, Cow::Borrowed("1\n2\n")));
+    assert!(checked.notes.is_empty());
+}
+
+#[test]
+fn normalize_test() {
+    let checked = check("\u{feff}1\r\n2\r\n").unwrap();
+    assert_eq!(checked.data, "1\n2\n");
+    assert_eq!(checked.notes, ["Removed byte order mark", "Converted CRLF line endings to LF"]);
+
+    let checked = check("1\n2").unwrap();
+    assert_eq!(checked.data, "1\n2\n");
+    assert_eq!(checked.notes.len(), 1);
+    assert!(checked.notes[0].starts_with("Added missing trailing newline"));
+}
+
+#[test]
+fn reject_test() {
+    assert_eq!(check(""), Err("Input is empty".to_owned()));
+    assert_eq!(check("\u{feff}\n \n"), Err("Input is empty".to_owned()));
+
+    let login = "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n";
+    assert!(check(login).unwrap_err().contains("login message"));
+
+    let html = "<!DOCTYPE html>\n<html lang=\"en-us\">\n<head>\n<title>404</title>";
+    assert!(check(html).unwrap_err().contains("HTML page"));
+    assert!(check("\n<html><body>500</body></html>").unwrap_err().contains("HTML page"));
+    assert!(check("ab\0cd\n").unwrap_err().contains("NUL"));
+
+    // Angle brackets are common in real inputs.
+    assert!(check("<>^v\n").is_ok());
+    assert!(check("<x=-1, y=0, z=2>\n").is_ok());
+}
@@ -0,0 +1,70 @@
+use aoc::error::AocResult;
+use aoc::runner::isolate::*;
+use aoc::runner::record::Status;
+use aoc::runner::stats::Sampling;
+use aoc::solution::{find, Answer, Entry};
+use std::any::Any;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+fn parse(_: &str) -> AocResult<Box<dyn Any>> {
+    Ok(Box::new(()))
+}
+
+fn answer(_: &dyn Any) -> Answer {
+    Answer::Integer(42)
+}
+
+fn fail(_: &dyn Any) -> Answer {
+    panic!("Something went wrong")
+}
+
+#[allow(clippy::empty_loop)]
+fn forever(_: &dyn Any) -> Answer {
+    loop {}
+}
+
+static FAIL: Entry = Entry { year: 2000, day: 1, parse, part1: answer, part2: fail };
+static FOREVER: Entry = Entry { year: 2000, day: 2, parse, part1: answer, part2: forever };
+
+#[test]
+fn ok_test() {
+    let entry = find(2019, 1).unwrap();
+    let timeout = Some(Duration::from_secs(10));
+
+    let record =
+        solve(entry, PathBuf::from("example"), "100756".to_owned(), Sampling::default(), timeout);
+    assert_eq!(record.status, Status::Ok);
+    assert_eq!(record.part1, Some(Answer::Integer(33583)));
+    assert_eq!(record.error, None);
+}
+
+#[test]
+fn panic_test() {
+    let record =
+        solve(&FAIL, PathBuf::from("example"), "input\n".to_owned(), Sampling::default(), None);
+    assert_eq!(record.status, Status::Panicked);
+    assert_eq!(record.part1, Some(Answer::Integer(42)));
+    assert_eq!(record.part2, None);
+
+    let error = record.error.unwrap();
+    assert!(error.starts_with("Something went wrong at tests/runner/isolate.rs:"), "{error}");
+}
+
+#[test]
+fn timeout_test() {
+    let instant = Instant::now();
+    let timeout = Some(Duration::from_millis(100));
+
+    let record = solve(
+        &FOREVER,
+        PathBuf::from("example"),
+        "input\n".to_owned(),
+        Sampling::default(),
+        timeout,
+    );
+    assert_eq!(record.status, Status::Timeout);
+    assert_eq!(record.part2, None);
+    assert!(record.hash.is_some());
+    assert!(instant.elapsed() < Duration::from_secs(10));
+}
@@ -0,0 +1,33 @@
+use aoc::runner::jobs::*;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+#[test]
+fn order_test() {
+    let items = [50, 0, 30, 10, 0];
+
+    for jobs in [0, 1, 2, 5] {
+        let mut results = Vec::new();
+        let result = run(
+            &items,
+            jobs,
+            |&millis| {
+                sleep(Duration::from_millis(millis));
+                millis * 2
+            },
+            |&item, doubled| results.push((item, doubled)),
+        );
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(results, [(50, 100), (0, 0), (30, 60), (10, 20), (0, 0)]);
+    }
+}
+
+#[test]
+fn parallel_test() {
+    let instant = Instant::now();
+    let result = run(&[200; 4], 4, |&millis| sleep(Duration::from_millis(millis)), |_, ()| ());
+
+    assert_eq!(result, Ok(()));
+    assert!(instant.elapsed() < Duration::from_millis(700));
+}
@@ -0,0 +1,27 @@
+use aoc::runner::memory::*;
+use std::alloc::{GlobalAlloc, Layout};
+
+#[test]
+fn meter_test() {
+    // Calls the allocator directly, so this works whether or not it's the global allocator.
+    let layout = Layout::from_size_align(1000, 8).unwrap();
+    let meter = Meter::start();
+
+    unsafe {
+        let first = Counting.alloc(layout);
+        let second = Counting.alloc_zeroed(layout);
+        Counting.dealloc(first, layout);
+        let second = Counting.realloc(second, layout, 3000);
+        Counting.dealloc(second, Layout::from_size_align(3000, 8).unwrap());
+    }
+
+    // Figures are only reported when built with the `memory` feature.
+    assert_eq!(enabled(), cfg!(feature = "memory"));
+    let Some(usage) = meter.stop() else {
+        assert!(!enabled());
+        return;
+    };
+    assert!(usage.allocations >= 3);
+    assert!(usage.bytes >= 5000);
+    assert!(usage.peak >= 3000);
+}
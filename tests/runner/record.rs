@@ -0,0 +1,87 @@
+use aoc::runner::record::*;
+use aoc::runner::stats::Sampling;
+use aoc::solution::{find, Answer};
+use std::path::PathBuf;
+
+#[test]
+fn solve_test() {
+    let entry = find(2019, 1).unwrap();
+
+    let record = solve(entry, PathBuf::from("example"), "100756", Sampling::default());
+    assert_eq!(record.status, Status::Ok);
+    assert_eq!(record.part1, Some(Answer::Integer(33583)));
+    assert_eq!(record.hash.as_deref(), Some("c62442ec1dcf24dd5f543b7321122c94"));
+
+    let record = solve(entry, PathBuf::from("example"), "not a number", Sampling::default());
+    assert_eq!(record.status, Status::InvalidInput);
+    assert_eq!(record.part1, None);
+
+    let entry = find(2019, 4).unwrap();
+    let record = solve(entry, PathBuf::from("example"), "not a range", Sampling::default());
+    assert_eq!(record.status, Status::Panicked);
+    assert_eq!(record.part1, None);
+}
+
+#[test]
+fn sampling_test() {
+    let entry = find(2019, 1).unwrap();
+    let sampling = Sampling { warmup: 2, iterations: 5 };
+
+    let record = solve(entry, PathBuf::from("example"), "100756", sampling);
+    assert_eq!(record.part2, Some(Answer::Integer(50346)));
+    assert_eq!(record.parse_time.samples, 5);
+    assert_eq!(record.part1_time.samples, 5);
+    assert!(record.part1_time.min <= record.part1_time.median);
+    assert!(record.part1_time.median <= record.part1_time.p95);
+}
+
+#[test]
+fn invalid_input_test() {
+    let entry = find(2019, 1).unwrap();
+
+    let record = solve(entry, PathBuf::from("example"), "<!DOCTYPE html>", Sampling::default());
+    assert_eq!(record.status, Status::InvalidInput);
+    assert_eq!(record.part1, None);
+    assert!(record.error.unwrap().contains("HTML"));
+
+    let record = solve(entry, PathBuf::from("example"), "12\r\n14", Sampling::default());
+    assert_eq!(record.status, Status::Ok);
+    assert_eq!(record.part1, Some(Answer::Integer(4)));
+    assert_eq!(record.notes.len(), 2);
+}
+
+#[test]
+fn parse_error_test() {
+    let entry = find(2023, 19).unwrap();
+    let data = "in{x<10:A,R}\nqs{s>3448:A,lnx}\n\n{x=787,m=2655,a=one,s=2876}\n";
+
+    let record = solve(entry, PathBuf::from("example"), data, Sampling::default());
+    assert_eq!(record.status, Status::InvalidInput);
+    assert_eq!(record.part1, None);
+    assert_eq!(
+        record.error.as_deref(),
+        Some(
+            "line 4, column 17: Expected a number, found \"one\"\n  |\n\
+             4 | {x=787,m=2655,a=one,s=2876}\n  |                 ^"
+        )
+    );
+
+    let record = solve(entry, PathBuf::from("example"), "in{y<10:A,R}\n\n", Sampling::default());
+    assert_eq!(record.status, Status::InvalidInput);
+    assert!(record.error.unwrap().starts_with("line 1, column 4: Expected one of x, m, a or s"));
+}
+
+#[test]
+fn memory_test() {
+    let entry = find(2019, 1).unwrap();
+    let record = solve(entry, PathBuf::from("example"), "12\n14\n1969\n", Sampling::default());
+
+    // Only counted when built with the `memory` feature.
+    if cfg!(feature = "memory") {
+        let parse = record.parse_memory.unwrap();
+        assert!(parse.allocations >= 1);
+        assert!(parse.bytes >= 3 * 4);
+        assert!(parse.peak >= 3 * 4);
+        assert!(record.part1_memory.is_some());
+    }
+}
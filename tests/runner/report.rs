@@ -0,0 +1,80 @@
+use aoc::runner::answers::Expected;
+use aoc::runner::record::{solve, Record, Status};
+use aoc::runner::report::*;
+use aoc::runner::stats::Sampling;
+use aoc::solution::find;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+fn row(year: u32, day: u32, status: Status, millis: u64, title: Option<&str>) -> Row {
+    let mut record = Record::new(find(year, day).unwrap(), PathBuf::from("input"), status);
+    record.part1_time.median = Duration::from_millis(millis);
+    Row { record, title: title.map(str::to_owned) }
+}
+
+fn rows() -> Vec<Row> {
+    vec![
+        row(2023, 7, Status::Ok, 3, Some("Camel <Cards> | Poker")),
+        row(2023, 8, Status::MissingInput, 0, None),
+        row(2024, 1, Status::Ok, 1, Some("Historian Hysteria")),
+        row(2024, 2, Status::Panicked, 0, None),
+    ]
+}
+
+#[test]
+fn title_test() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    assert_eq!(title(root, 2025, 8).as_deref(), Some("Playground"));
+    assert_eq!(title(root, 2025, 1).as_deref(), Some("Secret Entrance"));
+    assert_eq!(title(root, 2019, 1).as_deref(), Some("The Tyranny of the Rocket Equation"));
+    assert_eq!(title(root, 2015, 1), None);
+    assert_eq!(title(root, 2024, 0), None);
+    assert_eq!(title(root, 2024, 26), None);
+
+    // Every registered day has a title.
+    let missing: Vec<_> =
+        aoc::available().filter(|&(year, day)| title(root, year, day).is_none()).collect();
+    assert!(missing.is_empty(), "No title for {missing:?}");
+}
+
+#[test]
+fn stars_test() {
+    let entry = find(2019, 1).unwrap();
+    let mut record = solve(entry, PathBuf::from("example"), "100756", Sampling::default());
+    assert_eq!(stars(&record), 2);
+
+    record.verify(&Expected { part1: Some("1".to_owned()), part2: None });
+    assert_eq!(stars(&record), 1);
+
+    record.status = Status::Timeout;
+    assert_eq!(stars(&record), 0);
+}
+
+#[test]
+fn markdown_test() {
+    let markdown = markdown(&rows(), 1);
+    let lines: Vec<_> = markdown.lines().collect();
+
+    assert_eq!(lines[2], "4 days, 4 stars, 4.0 ms in total.");
+    assert_eq!(lines[4], "## 2023");
+    assert_eq!(
+        lines[8],
+        "| 7 | Camel <Cards> \\| Poker | **⭐⭐** | **0 µs** | **3.0 ms** | **0 µs** | **3.0 ms** |"
+    );
+    assert_eq!(lines[9], "| 8 |  | missing input |  |  |  |  |");
+    assert_eq!(lines[11], "## 2024");
+    assert_eq!(lines[15], "| 1 | Historian Hysteria | ⭐⭐ | 0 µs | 1000 µs | 0 µs | 1000 µs |");
+    assert_eq!(lines[16], "| 2 |  | panicked |  |  |  |  |");
+}
+
+#[test]
+fn html_test() {
+    let html = html(&rows(), 2);
+
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<h2>2023</h2>"));
+    assert!(html.contains("<tr class=\"slow\"><td>7</td><td>Camel &lt;Cards&gt; | Poker</td>"));
+    assert!(html.contains("<tr class=\"slow\"><td>1</td><td>Historian Hysteria</td>"));
+    assert!(html.contains("<tr><td>8</td><td></td><td>missing input</td>"));
+    assert!(html.ends_with("</html>\n"));
+}
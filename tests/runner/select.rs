@@ -0,0 +1,79 @@
+use aoc::runner::select::*;
+
+fn selection(args: &[&str]) -> Selection {
+    Selection::parse(args).unwrap()
+}
+
+#[test]
+fn empty_test() {
+    let selection = selection(&[]);
+    assert!(selection.is_empty());
+    assert!(selection.matches(2015, 1));
+}
+
+#[test]
+fn single_test() {
+    assert!(selection(&["year2024"]).matches(2024, 7));
+    assert!(selection(&["2024"]).matches(2024, 7));
+    assert!(!selection(&["2024"]).matches(2023, 7));
+    assert!(selection(&["year2024::day01"]).matches(2024, 1));
+    assert!(selection(&["2024::1"]).matches(2024, 1));
+    assert!(!selection(&["year2024::day01"]).matches(2024, 2));
+}
+
+#[test]
+fn range_and_list_test() {
+    let range = selection(&["year2024::day01-05"]);
+    assert!(range.matches(2024, 1) && range.matches(2024, 5));
+    assert!(!range.matches(2024, 6) && !range.matches(2023, 3));
+
+    let list = selection(&["2023,2024"]);
+    assert!(list.matches(2023, 9) && list.matches(2024, 9));
+    assert!(!list.matches(2022, 9));
+
+    let mixed = selection(&["year2015-year2017::day3,day20-25"]);
+    assert!(mixed.matches(2016, 3) && mixed.matches(2017, 25));
+    assert!(!mixed.matches(2016, 4) && !mixed.matches(2018, 3));
+}
+
+#[test]
+fn day_across_years_test() {
+    let selection = selection(&["day10"]);
+    assert!(selection.matches(2019, 10) && selection.matches(2024, 10));
+    assert!(!selection.matches(2024, 11));
+}
+
+#[test]
+fn glob_test() {
+    let selection = selection(&["year202*::day1?"]);
+    assert!(selection.matches(2020, 10) && selection.matches(2024, 19));
+    assert!(!selection.matches(2024, 1) && !selection.matches(2019, 10));
+
+    assert!(Spec::Glob("*5".to_owned()).matches(5, 2));
+    assert!(Spec::Glob("*".to_owned()).matches(2015, 4));
+}
+
+#[test]
+fn exclusion_test() {
+    let only = selection(&["!year2024::day24"]);
+    assert!(!only.matches(2024, 24));
+    assert!(only.matches(2024, 23));
+
+    let combined = selection(&["2024", "2019", "!day01-10"]);
+    assert!(combined.matches(2024, 11) && combined.matches(2019, 11));
+    assert!(!combined.matches(2024, 10) && !combined.matches(2023, 11));
+}
+
+#[test]
+fn error_test() {
+    for arg in ["", "year", "2024::", "20x4", "day05-01", "2024::day1::2", "day1-", "day1a*", "!"] {
+        let result = Selection::parse(&[arg]);
+        assert!(result.is_err(), "{arg} should be rejected");
+    }
+
+    assert_eq!(
+        Selection::parse(&["year2024::dayfive"]),
+        Err("Invalid selection \"year2024::dayfive\": expected a day number, found \"dayfive\""
+            .to_owned())
+    );
+}
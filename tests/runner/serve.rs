@@ -0,0 +1,133 @@
+use aoc::runner::isolate::Abandoned;
+use aoc::runner::serve::*;
+use aoc::runner::stats::Sampling;
+use aoc::site::http::{send, Request};
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+fn post(path: &str, body: &str) -> (u16, String) {
+    respond("POST", path, body.to_owned(), Sampling::default(), None, &Abandoned::default())
+}
+
+fn get(path: &str) -> (u16, String) {
+    respond("GET", path, String::new(), Sampling::default(), None, &Abandoned::default())
+}
+
+#[test]
+fn list_test() {
+    let (status, body) = get("/");
+    assert_eq!(status, 200);
+    assert!(body.starts_with("[{\"year\":2019,\"day\":1,\"path\":\"/year2019/day01\"},"));
+}
+
+#[test]
+fn solve_test() {
+    let (status, body) = post("/year2019/day01", "100756\n");
+    assert_eq!(status, 200);
+    assert!(body.starts_with("{\"year\":2019,\"day\":1,\"status\":\"ok\",\"part1\":33583,"));
+    assert!(body.contains("\"part2\":50346,\"iterations\":1,"));
+}
+
+#[test]
+fn failure_test() {
+    let (status, body) = post("/year2019/day02", "1,x\n");
+    assert_eq!(status, 422);
+    assert!(body.contains("\"status\":\"invalid_input\""));
+    assert!(body.contains("\"error\":\"line 1, column 3: Expected a number"));
+
+    let (status, body) = post("/year2019/day01", "not a number\n");
+    assert_eq!(status, 422);
+    assert!(body.contains("\"error\":\"line 1, column 1: Expected a number"));
+
+    let (status, body) = post("/year2019/day04", "not a range\n");
+    assert_eq!(status, 500);
+    assert!(body.contains("\"status\":\"panicked\""));
+}
+
+#[test]
+fn error_test() {
+    assert_eq!(
+        post("/year2023/day26", "1\n"),
+        (404, "{\"error\":\"No solution for 2023 Day 26\"}".to_owned())
+    );
+    assert_eq!(post("/favicon.ico", "").0, 404);
+    assert_eq!(get("/year2019/day01").0, 405);
+    assert_eq!(post("/", "").0, 405);
+}
+
+#[test]
+fn server_test() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    thread::spawn(move || serve(listener, 2, Sampling::default(), Some(Duration::from_secs(10))));
+
+    let url = format!("{base_url}/year2019/day01");
+    let response = send(&Request { url: &url, cookie: None, form: Some("12\n14\n") }).unwrap();
+    assert_eq!(response.status, 200);
+    assert!(response.body.contains("\"part1\":4,\"part2\":4,"));
+
+    let response = send(&Request { url: &base_url, cookie: None, form: None }).unwrap();
+    assert_eq!(response.status, 200);
+    assert!(response.body.contains("/year2023/day07"));
+}
+
+#[test]
+fn limits_test() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    thread::spawn(move || serve(listener, 1, Sampling::default(), Some(Duration::from_secs(10))));
+
+    let raw = |request: &str| {
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        stream.shutdown(std::net::Shutdown::Write).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    };
+
+    // Oversized bodies are refused up front, while a body claiming more than it sends is reported.
+    let huge = format!("POST /year2019/day01 HTTP/1.1\r\nContent-Length: {}\r\n\r\n", MAX_BODY + 1);
+    assert!(raw(&huge).starts_with("HTTP/1.1 413 "));
+    let short = "POST /year2019/day01 HTTP/1.1\r\nContent-Length: 1000000\r\n\r\n12\n";
+    assert!(raw(short).contains("Request body is shorter than expected"));
+
+    // A single worker still serves every connection in turn.
+    for _ in 0..3 {
+        let request = "POST /year2019/day01 HTTP/1.1\r\nContent-Length: 3\r\n\r\n12\n";
+        assert!(raw(request).contains("\"part1\":2,"));
+    }
+}
+
+#[test]
+fn abandoned_test() {
+    let abandoned = Abandoned::default();
+    // Takes around a second in debug builds, so always times out yet eventually finishes.
+    let slow = || {
+        let timeout = Some(Duration::from_millis(1));
+        let body = "0-3000000\n".to_owned();
+        respond("POST", "/year2019/day04", body, Sampling::default(), timeout, &abandoned)
+    };
+
+    for _ in 0..MAX_ABANDONED {
+        assert_eq!(slow().0, 504);
+    }
+    assert_eq!(abandoned.count(), MAX_ABANDONED);
+
+    // Further solves are refused rather than starting yet more threads.
+    for _ in 0..3 {
+        let (status, body) = slow();
+        assert_eq!(status, 503, "{body}");
+    }
+    assert_eq!(abandoned.count(), MAX_ABANDONED);
+
+    // Slots are released as the abandoned solutions finish.
+    let instant = std::time::Instant::now();
+    while abandoned.count() > 0 {
+        assert!(instant.elapsed() < Duration::from_secs(60), "Abandoned solutions never finished");
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(slow().0, 504);
+}
@@ -0,0 +1,36 @@
+use aoc::runner::stats::*;
+use std::time::Duration;
+
+fn micros(samples: &[u64]) -> Vec<Duration> {
+    samples.iter().map(|&n| Duration::from_micros(n)).collect()
+}
+
+#[test]
+fn single_sample_test() {
+    let stats = Stats::new(&micros(&[7]));
+    let seven = Duration::from_micros(7);
+    assert_eq!(stats, Stats { samples: 1, min: seven, median: seven, mean: seven, p95: seven });
+}
+
+#[test]
+fn odd_and_even_median_test() {
+    assert_eq!(Stats::new(&micros(&[5, 1, 3])).median, Duration::from_micros(3));
+    assert_eq!(Stats::new(&micros(&[4, 1, 3, 2])).median, Duration::from_nanos(2500));
+    assert_eq!(Stats::new(&micros(&[4, 1, 3, 2])).mean, Duration::from_nanos(2500));
+}
+
+#[test]
+fn percentile_test() {
+    let samples: Vec<_> = (1..=100).rev().collect();
+    let stats = Stats::new(&micros(&samples));
+    assert_eq!(stats.min, Duration::from_micros(1));
+    assert_eq!(stats.p95, Duration::from_micros(95));
+
+    let stats = Stats::new(&micros(&[1, 2, 3, 100]));
+    assert_eq!(stats.p95, Duration::from_micros(100));
+}
+
+#[test]
+fn empty_test() {
+    assert_eq!(Stats::new(&[]), Stats::default());
+}
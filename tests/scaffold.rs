@@ -0,0 +1,127 @@
+use aoc::scaffold::*;
+
+const LIB: &str = "\
+solutions! {
+    // year2015 \"Help Santa.\"
+    //     day01, day02, day03;
+    //
+    year2019 \"Rescue Santa.\"
+        day01: parse, day03
+        with intcode;
+}
+";
+
+const TESTS: &str = "\
+// test!(year2015
+//     day01, day02
+// );
+//
+test!(year2019
+    day01
+    // day02, day03
+);
+
+mod registry {}
+";
+
+#[test]
+fn parse_day_test() {
+    assert_eq!(parse_day("year2024::day05"), Ok((2024, 5)));
+    assert_eq!(parse_day("2024::5"), Ok((2024, 5)));
+    assert!(parse_day("year2024").is_err());
+    assert!(parse_day("year2024::day1-5").is_err());
+    assert!(parse_day("year2014::day01").is_err());
+    assert!(parse_day("year2025::day13").is_err());
+}
+
+#[test]
+fn register_existing_year_test() {
+    let lib = register(LIB, 2019, 2, Kind::Solutions).unwrap().unwrap();
+    assert!(lib.contains("    year2019 \"Rescue Santa.\"\n        day01: parse, day02, day03\n        with intcode;\n"));
+
+    let tests = register(TESTS, 2019, 3, Kind::Tests).unwrap().unwrap();
+    assert!(tests.contains("test!(year2019\n    day01, day03\n    // day02, day03\n);\n"));
+}
+
+#[test]
+fn register_commented_year_test() {
+    let lib = register(LIB, 2015, 2, Kind::Solutions).unwrap().unwrap();
+    assert!(lib.starts_with("solutions! {\n    year2015 \"Help Santa.\"\n        day02;\n    //\n"));
+
+    let tests = register(TESTS, 2015, 2, Kind::Tests).unwrap().unwrap();
+    assert!(tests.starts_with("test!(year2015\n    day02\n);\n//\n"));
+}
+
+#[test]
+fn register_new_year_test() {
+    let lib = register(LIB, 2025, 1, Kind::Solutions).unwrap().unwrap();
+    assert!(
+        lib.ends_with("with intcode;\n\n    year2025 \"Advent of Code 2025\"\n        day01;\n}\n")
+    );
+
+    let tests = register(TESTS, 2025, 1, Kind::Tests).unwrap().unwrap();
+    assert!(tests.contains(");\n//\ntest!(year2025\n    day01\n);\n\nmod registry {}\n"));
+}
+
+#[test]
+fn register_new_year_in_order_test() {
+    let tests = "\
+test!(year2019
+    day01
+);
+//
+// test!(year2023
+//     day01
+// );
+//
+test!(year2024
+    day01
+);
+//
+// Every 2025 day has unit tests in the solution itself.
+
+mod registry {}
+";
+    // Comments about the previous years stay above the new year.
+    let added = register(tests, 2026, 1, Kind::Tests).unwrap().unwrap();
+    assert!(added.ends_with(
+        "// Every 2025 day has unit tests in the solution itself.\n//\ntest!(year2026\n    day01\n);\n\nmod registry {}\n"
+    ));
+
+    // A year between existing ones goes before the next year's separator.
+    let added = register(tests, 2020, 1, Kind::Tests).unwrap().unwrap();
+    assert!(added.starts_with(
+        "test!(year2019\n    day01\n);\n//\ntest!(year2020\n    day01\n);\n//\n// test!(year2023\n"
+    ));
+
+    let lib = register(LIB, 2016, 1, Kind::Solutions).unwrap().unwrap();
+    assert!(lib.contains("    //     day01, day02, day03;\n\n    year2016 \"Advent of Code 2016\"\n        day01;\n    //\n    year2019"));
+}
+
+#[test]
+fn register_idempotent_test() {
+    assert_eq!(register(LIB, 2019, 3, Kind::Solutions), Ok(None));
+    assert_eq!(register(LIB, 2019, 1, Kind::Solutions), Ok(None));
+    assert_eq!(register(TESTS, 2019, 1, Kind::Tests), Ok(None));
+    assert!(register("", 2019, 2, Kind::Solutions).is_err());
+    assert!(register("", 2019, 2, Kind::Tests).is_err());
+}
+
+#[test]
+fn wrap_test() {
+    let lib = (4..=24).fold(LIB.to_owned(), |lib, day| {
+        register(&lib, 2019, day, Kind::Solutions).unwrap().unwrap_or(lib)
+    });
+    assert!(lib.lines().all(|line| line.len() <= 100));
+    assert!(lib.contains("day23, day24\n        with intcode;"));
+}
+
+#[test]
+fn template_test() {
+    assert!(solution(2024, 5).starts_with("//! # Day 5: [Title]\n"));
+    assert!(test(2024, 5).starts_with("use aoc::year2024::day05::*;\n"));
+
+    // New tests never pass before the day is solved.
+    let ignored = format!("#[test]\n#[ignore = \"{UNFINISHED}\"]\nfn part1_test()");
+    assert!(test(2024, 5).contains(&ignored));
+}
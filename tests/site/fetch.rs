@@ -0,0 +1,74 @@
+use super::server;
+use aoc::site::client::Client;
+use aoc::site::fetch::*;
+use std::env::temp_dir;
+use std::fs::{read_to_string, remove_dir_all};
+use std::time::{Duration, Instant};
+
+fn reply(path: &str) -> (u16, String) {
+    match path {
+        "/2024/day/1/input" => (200, "3   4\n4   3\n".to_owned()),
+        "/2024/day/2/input" => (200, "<!DOCTYPE html>\n<html>Log in</html>".to_owned()),
+        "/2024/day/3/input" => (200, String::new()),
+        _ => (404, "Please don't repeatedly request this endpoint before it unlocks!".to_owned()),
+    }
+}
+
+#[test]
+fn fetch_test() {
+    let (base_url, requests) = server::start(reply);
+    let dir = temp_dir().join(format!("aoc-fetch-test-{}", std::process::id()));
+    let path = |day| dir.join("year2024").join(format!("day{day:02}.txt"));
+    let mut client = Client::new(&base_url, "session=abc\n", Duration::ZERO);
+
+    assert_eq!(fetch(&mut client, 2024, 1, &path(1)), Ok(Outcome::Downloaded));
+    assert_eq!(read_to_string(path(1)).unwrap(), "3   4\n4   3\n");
+    assert!(requests.lock().unwrap()[0].contains("Cookie: session=abc\r\n"));
+
+    // Cached files are never downloaded again.
+    assert_eq!(fetch(&mut client, 2024, 1, &path(1)), Ok(Outcome::Cached));
+    assert_eq!(requests.lock().unwrap().len(), 1);
+
+    // Error pages, empty responses and unreleased puzzles are not saved.
+    for day in [2, 3, 4] {
+        assert!(fetch(&mut client, 2024, day, &path(day)).is_err());
+        assert!(!path(day).exists());
+    }
+    let error = fetch(&mut client, 2024, 4, &path(4)).unwrap_err();
+    assert!(error.starts_with("Server replied with status 404: Please don't"), "{error}");
+
+    remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn throttle_test() {
+    let (base_url, _) = server::start(reply);
+    let mut client = Client::new(&base_url, "abc", Duration::from_millis(100));
+    let instant = Instant::now();
+
+    for _ in 0..3 {
+        client.get("/2024/day/1/input").unwrap();
+    }
+    assert!(instant.elapsed() >= Duration::from_millis(200));
+}
+
+#[test]
+fn calendar_test() {
+    assert_eq!(days(2024), 25);
+    assert_eq!(days(2025), 12);
+    assert_eq!(calendar((2016, 25)).count(), 50);
+    assert_eq!(calendar((2025, 12)).last(), Some((2025, 12)));
+    assert_eq!(calendar((2024, 3)).count(), 9 * 25 + 3);
+}
+
+#[test]
+fn unlocked_test() {
+    // 2024-12-01 04:59:59 and 05:00:00 UTC, either side of midnight in New York.
+    assert_eq!(unlocked(1_733_029_199), (2023, 25));
+    assert_eq!(unlocked(1_733_029_200), (2024, 1));
+    // 2024-12-31 and 2025-03-01, after the last puzzle of the year.
+    assert_eq!(unlocked(1_735_646_400), (2024, 25));
+    assert_eq!(unlocked(1_740_787_200), (2024, 25));
+    // 2025-12-20, when 2025 has only 12 days.
+    assert_eq!(unlocked(1_766_232_000), (2025, 12));
+}
@@ -0,0 +1,45 @@
+use super::server;
+use aoc::site::http::*;
+
+#[test]
+fn get_test() {
+    let (base_url, requests) = server::start(|path| (200, format!("You asked for {path}\n")));
+    let url = format!("{base_url}/2024/day/1/input");
+
+    let response = send(&Request { url: &url, cookie: Some("session=abc"), form: None }).unwrap();
+    assert_eq!(
+        response,
+        Response { status: 200, body: "You asked for /2024/day/1/input\n".to_owned() }
+    );
+
+    let request = &requests.lock().unwrap()[0];
+    assert!(request.starts_with("GET /2024/day/1/input HTTP/1.1\r\n"));
+    assert!(request.contains("Cookie: session=abc\r\n"));
+    assert!(request.contains(&format!("User-Agent: {USER_AGENT}\r\n")));
+}
+
+#[test]
+fn post_test() {
+    let (base_url, requests) = server::start(|_| (404, String::new()));
+    let url = format!("{base_url}/2024/day/1/answer");
+
+    let response =
+        send(&Request { url: &url, cookie: None, form: Some("level=1&answer=42") }).unwrap();
+    assert_eq!(response.status, 404);
+
+    let request = &requests.lock().unwrap()[0];
+    assert!(request.starts_with("POST /2024/day/1/answer HTTP/1.1\r\n"));
+    assert!(request.ends_with("\r\n\r\nlevel=1&answer=42"));
+}
+
+#[test]
+fn error_test() {
+    assert!(send(&Request { url: "ftp://example.com", cookie: None, form: None }).is_err());
+    assert!(send(&Request { url: "http://127.0.0.1:1/", cookie: None, form: None }).is_err());
+}
+
+#[test]
+fn encode_test() {
+    assert_eq!(encode("a-Z_0.~"), "a-Z_0.~");
+    assert_eq!(encode("1,2 3&x=é"), "1%2C2%203%26x%3D%C3%A9");
+}
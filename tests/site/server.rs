@@ -0,0 +1,54 @@
+//! Local stand-in for the Advent of Code website.
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Every request received, as the request line, headers and body.
+pub type Requests = Arc<Mutex<Vec<String>>>;
+
+/// Serves requests in the background, replying with the status and body returned by `reply`
+/// for each request path. Returns the base URL and the log of requests.
+pub fn start(reply: fn(&str) -> (u16, String)) -> (String, Requests) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Requests::default();
+    let log = requests.clone();
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(&mut stream);
+            let mut request = String::new();
+            let mut length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    length = value.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+
+            let path = request.split_whitespace().nth(1).unwrap().to_owned();
+            log.lock().unwrap().push(request);
+
+            let (status, body) = reply(&path);
+            let response = format!(
+                "HTTP/1.1 {status} Whatever\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+
+    (base_url, requests)
+}
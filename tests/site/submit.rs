@@ -0,0 +1,72 @@
+use super::server;
+use aoc::site::client::Client;
+use aoc::site::submit::*;
+use std::env::temp_dir;
+use std::fs::{read_to_string, remove_file};
+use std::time::Duration;
+
+const RIGHT: &str = "<main>\n<article><p>That's the right answer!  You are one gold star closer to \
+    saving Christmas. <a href=\"/2024/day/1#part2\">[Continue to Part Two]</a></p></article>\n</main>";
+const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low.  If \
+    you're stuck, make sure you're using the full input data.  Please wait one minute before \
+    trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>";
+const WRONG: &str = "<article><p>That's not the right answer.  Because you have guessed \
+    incorrectly 4 times on this puzzle, please wait 5 minutes before trying again.</p></article>";
+const TOO_SOON: &str = "<article><p>You gave an answer too recently; you have to wait after \
+    submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>";
+const LEVEL: &str = "<article><p>You don't seem to be solving the right level.  Did you already \
+    complete it? <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>";
+
+#[test]
+fn parse_test() {
+    assert_eq!(parse(RIGHT), Reply::Correct);
+    assert_eq!(
+        parse(TOO_LOW),
+        Reply::Wrong { hint: Hint::TooLow, wait: Some(Duration::from_secs(60)) }
+    );
+    assert_eq!(
+        parse(WRONG),
+        Reply::Wrong { hint: Hint::None, wait: Some(Duration::from_secs(300)) }
+    );
+    assert_eq!(parse(TOO_SOON), Reply::Wait(Duration::from_secs(65)));
+    assert_eq!(parse(LEVEL), Reply::WrongLevel);
+    assert_eq!(parse("<article><p>Huh?</p></article>"), Reply::Unknown("Huh?".to_owned()));
+}
+
+fn reply(path: &str) -> (u16, String) {
+    match path {
+        "/2024/day/1/answer" => (200, TOO_LOW.to_owned()),
+        "/2024/day/2/answer" => (200, RIGHT.to_owned()),
+        _ => (404, String::new()),
+    }
+}
+
+#[test]
+fn submit_test() {
+    let (base_url, requests) = server::start(reply);
+    let mut client = Client::new(&base_url, "abc", Duration::ZERO);
+    let store = temp_dir().join(format!("aoc-submit-test-{}.txt", std::process::id()));
+    let now = 1_000_000;
+
+    // Wrong answers are recorded along with the cooldown.
+    let reply = submit(&mut client, (2024, 1, 1), "1 000", &store, now).unwrap();
+    assert!(matches!(reply, Reply::Wrong { hint: Hint::TooLow, .. }));
+    assert!(requests.lock().unwrap()[0].ends_with("\r\n\r\nlevel=1&answer=1%20000"));
+    assert_eq!(read_to_string(&store).unwrap(), "part1_too_low: 1 000\nwait_until: 1000060\n");
+
+    // Refused during the cooldown without contacting the server.
+    let error = submit(&mut client, (2024, 1, 1), "2000", &store, now + 30).unwrap_err();
+    assert_eq!(error, "Too soon to submit again, wait another 30s");
+    let error = submit(&mut client, (2024, 1, 1), "1 000", &store, now + 60).unwrap_err();
+    assert_eq!(error, "1 000 was already rejected for part 1");
+    assert_eq!(requests.lock().unwrap().len(), 1);
+
+    // Correct answers become the expected answer and are never submitted again.
+    assert_eq!(submit(&mut client, (2024, 2, 1), "2000", &store, now + 60), Ok(Reply::Correct));
+    assert!(read_to_string(&store).unwrap().ends_with("\npart1: 2000\n"));
+    let error = submit(&mut client, (2024, 2, 1), "2001", &store, now + 120).unwrap_err();
+    assert_eq!(error, "Part 1 was already solved with 2000");
+    assert_eq!(requests.lock().unwrap().len(), 2);
+
+    remove_file(store).unwrap();
+}
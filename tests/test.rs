@@ -1,36 +1,61 @@
-// Templates
+// New days are created from `src/template.rs` with `cargo run -- new yearYYYY::dayDD`.
 
-// pub fn parse(_input: &str) -> Vec<u32> {
-//     vec![]
-// }
-//
-// pub fn part1(_input: &[u32]) -> u32 {
-//     123
-// }
-//
-// pub fn part2(_input: &[u32]) -> u32 {
-//     456
-// }
+/// The template for new days must always compile.
+#[allow(dead_code)]
+#[path = "../src/template.rs"]
+mod template;
 
-// use aoc::year2024::day00::*;
-//
-// const EXAMPLE: &str = "";
-//
-// #[test]
-// fn part1_test() {
-//     let input = parse(EXAMPLE);
-//     assert_eq!(part1(&input), 123);
-// }
-//
-// #[test]
-// fn part2_test() {
-//     let input = parse(EXAMPLE);
-//     assert_eq!(part2(&input), 456);
-// }
+/// Counts allocations for `runner::record::memory_test` when built with the `memory` feature.
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOCATOR: aoc::runner::memory::Counting = aoc::runner::memory::Counting;
+
+mod error;
+mod scaffold;
+
+mod util {
+    mod grid;
+    mod heap;
+    mod math;
+    mod md5;
+    mod parse;
+    mod point;
+    mod point3;
+}
+
+mod runner {
+    mod answers;
+    mod format;
+    mod history;
+    mod input;
+    mod isolate;
+    mod jobs;
+    mod memory;
+    mod record;
+    mod report;
+    mod select;
+    mod serve;
+    mod stats;
+}
+
+mod site {
+    mod fetch;
+    mod http;
+    mod server;
+    mod submit;
+}
 
 macro_rules! test {
     ($year:tt $($day:tt),*) => {
-        pub mod $year {$(pub mod $day;)*}
+        pub mod $year {
+            $(pub mod $day;)*
+
+            #[test]
+            fn registered_test() {
+                use aoc::solution::{find, number};
+                $(assert!(find(number(stringify!($year)), number(stringify!($day))).is_some());)*
+            }
+        }
     }
 }
 
@@ -55,8 +80,7 @@ macro_rules! test {
 // );
 //
 test!(year2019
-    day01, day02, day03, day05, day06
-    // day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+    day01, day02, day03, day04, day05, day06
 );
 //
 // test!(year2020
@@ -74,13 +98,121 @@ test!(year2019
 //     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
 // );
 //
-// test!(year2023
-//     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-//     day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-// );
+// Days 1 to 8, 10, 11, 13, 15, 19, 20 and 22 to 24 have unit tests in the solution itself.
+test!(year2023
+    day09, day12, day14, day16, day17, day18, day21
+);
 
 test!(year2024
-    day01, day22, day23, day24, day25
-    // day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    // day14, day15, day16, day17, day18, day19, day20
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
 );
+//
+// Every 2025 day has unit tests in the solution itself.
+
+mod registry {
+    use aoc::solution::*;
+
+    #[test]
+    fn sorted_and_unique_test() {
+        let keys: Vec<_> = solutions().iter().map(|s| (s.year, s.day)).collect();
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+        assert!(keys.iter().all(|&(year, day)| year >= 2015 && (1..=25).contains(&day)));
+    }
+
+    #[test]
+    fn find_test() {
+        let entry = find(2023, 7).unwrap();
+        assert_eq!((entry.year, entry.day), (2023, 7));
+        assert!(entry.path().ends_with("year2023/day07.txt"));
+        assert!(find(2023, 25).is_none());
+    }
+
+    #[test]
+    fn path_in_test() {
+        let entry = find(2023, 7).unwrap();
+        let path = entry.path_in(std::path::Path::new("/private/inputs"));
+        assert_eq!(path.to_str(), Some("/private/inputs/year2023/day07.txt"));
+    }
+
+    /// Every registered day needs tests, either a file registered with `test!` above or unit
+    /// tests inside the solution. `cargo run -- new yearYYYY::dayDD` adds a missing test file,
+    /// which only counts once its example is filled in.
+    #[test]
+    fn every_day_tested_test() {
+        use aoc::scaffold::{register, Kind, UNFINISHED};
+
+        let tests = include_str!("test.rs");
+        let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+        let read = |dir: &str, year: u32, day: u32| {
+            std::fs::read_to_string(root.join(format!("{dir}/year{year}/day{day:02}.rs")))
+        };
+        // Test files straight from `new` are ignored until their example is filled in.
+        let test_file = |year: u32, day: u32| {
+            register(tests, year, day, Kind::Tests) == Ok(None)
+                && read("tests", year, day).is_ok_and(|source| !source.contains(UNFINISHED))
+        };
+        let unit_tests = |year: u32, day: u32| {
+            read("src", year, day).is_ok_and(|source| source.contains("#[test]"))
+        };
+
+        let untested: Vec<_> = aoc::available()
+            .filter(|&(year, day)| !test_file(year, day) && !unit_tests(year, day))
+            .collect();
+        assert!(untested.is_empty(), "No tests for {untested:?}");
+    }
+
+    #[test]
+    fn number_test() {
+        assert_eq!(number("year2024"), 2024);
+        assert_eq!(number("day07"), 7);
+    }
+
+    #[test]
+    fn typed_and_erased_agree_test() {
+        let input = Puzzle::<2019, 1>::parse("100756").unwrap();
+        assert_eq!(Puzzle::<2019, 1>::part1(&input), Answer::Integer(33583));
+
+        let entry = find(2019, 1).unwrap();
+        let input = (entry.parse)("100756").unwrap();
+        assert_eq!((entry.part1)(input.as_ref()), Answer::Integer(33583));
+        assert_eq!((entry.part2)(input.as_ref()), Answer::Integer(50346));
+    }
+
+    #[test]
+    fn solve_test() {
+        let answers = aoc::solve(2019, 1, "12\r\n14\r\n").unwrap();
+        assert_eq!(answers, (Answer::Integer(4), Answer::Integer(4)));
+
+        let error = aoc::solve(2023, 26, "1\n").unwrap_err();
+        assert_eq!(error.message, "No solution for 2023 Day 26");
+        assert!(aoc::solve(2019, 1, "").is_err());
+        assert_eq!(aoc::solve(2019, 2, "1,x\n").unwrap_err().column, Some(3));
+    }
+
+    #[test]
+    fn available_test() {
+        let days: Vec<_> = aoc::available().collect();
+        assert_eq!(days.len(), solutions().len());
+        assert!(days.contains(&(2023, 7)));
+        assert!(!days.contains(&(2023, 25)));
+    }
+
+    #[test]
+    fn answer_conversion_test() {
+        assert_eq!(Answer::from(42_u32), Answer::Integer(42));
+        assert_eq!(Answer::from(-7_i64), Answer::Integer(-7));
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+        assert_eq!(Answer::from("co,de,ka,ta"), Answer::Text("co,de,ka,ta".to_owned()));
+    }
+
+    #[test]
+    fn answer_display_test() {
+        assert_eq!(Answer::Integer(-3).to_string(), "-3");
+        assert_eq!(Answer::Empty.to_string(), "");
+
+        let art = Answer::Art("#..#\n####".to_owned());
+        assert_eq!(art.lines().collect::<Vec<_>>(), ["#..#", "####"]);
+        assert_eq!(Answer::Empty.lines().count(), 0);
+    }
+}
@@ -0,0 +1,64 @@
+use aoc::error::AocError;
+use aoc::util::grid::Grid;
+use aoc::util::point::Point;
+
+const EXAMPLE: &str = "\
+..#.
+#.S.
+....";
+
+#[test]
+fn parse_test() {
+    let grid = Grid::parse(EXAMPLE);
+    assert_eq!((grid.width, grid.height), (4, 3));
+    assert_eq!(grid[Point::new(2, 0)], b'#');
+    assert_eq!(grid[Point::new(0, 1)], b'#');
+    assert_eq!(grid.get(Point::new(4, 0)), None);
+    assert_eq!(grid.get(Point::new(0, -1)), None);
+    assert_eq!(grid.to_string(), EXAMPLE);
+}
+
+#[test]
+fn try_parse_test() {
+    let input = "..#\n#.\n";
+    let error = AocError::at(input, 4, "Expected 3 characters, found 2");
+    assert_eq!(Grid::try_parse(input), Err(error));
+}
+
+#[test]
+fn find_test() {
+    let grid = Grid::parse(EXAMPLE);
+    assert_eq!(grid.find(b'S'), Some(Point::new(2, 1)));
+    assert_eq!(grid.find(b'E'), None);
+    assert_eq!(grid.position(|&b| b == b'#'), Some(Point::new(2, 0)));
+
+    let walls: Vec<_> = grid.positions(|&b| b == b'#').collect();
+    assert_eq!(walls, [Point::new(2, 0), Point::new(0, 1)]);
+}
+
+#[test]
+fn neighbours_test() {
+    let grid = Grid::parse(EXAMPLE);
+
+    let corner: Vec<_> = grid.neighbours4(Point::new(0, 0)).collect();
+    assert_eq!(corner, [Point::new(1, 0), Point::new(0, 1)]);
+    assert_eq!(grid.neighbours4(Point::new(1, 1)).count(), 4);
+
+    assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 3);
+    assert_eq!(grid.neighbours8(Point::new(3, 1)).count(), 5);
+    assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+}
+
+#[test]
+fn same_size_with_test() {
+    let grid = Grid::parse(EXAMPLE);
+    let mut seen = grid.same_size_with(false);
+    assert_eq!((seen.width, seen.height), (4, 3));
+
+    seen[Point::new(3, 2)] = true;
+    assert_eq!(seen.bytes.iter().filter(|&&b| b).count(), 1);
+    assert_eq!(seen.get(Point::new(3, 2)), Some(&true));
+
+    let chars = Grid::new(2, 2, '.');
+    assert_eq!(chars.to_string(), "..\n..");
+}
@@ -0,0 +1,75 @@
+use aoc::util::heap::*;
+
+#[test]
+fn min_heap_test() {
+    let mut heap = MinHeap::new();
+    assert!(heap.is_empty());
+
+    heap.push(5, "five");
+    heap.push(1, "one");
+    heap.push(3, "three");
+    assert_eq!(heap.len(), 3);
+    assert_eq!(heap.peek(), Some((&1, &"one")));
+
+    assert_eq!(heap.pop(), Some((1, "one")));
+    heap.push(2, "two");
+    assert_eq!(heap.pop(), Some((2, "two")));
+    assert_eq!(heap.pop(), Some((3, "three")));
+    assert_eq!(heap.pop(), Some((5, "five")));
+    assert_eq!(heap.pop(), None);
+}
+
+#[test]
+fn min_heap_unordered_value_test() {
+    // Values don't need any ordering of their own.
+    struct State(#[allow(dead_code)] Vec<f64>);
+
+    let mut heap = MinHeap::with_capacity(2);
+    heap.push(2, State(vec![0.5]));
+    heap.push(1, State(vec![]));
+    assert_eq!(heap.pop().map(|(cost, _)| cost), Some(1));
+
+    heap.clear();
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn bucket_queue_test() {
+    let mut queue = BucketQueue::new();
+    queue.push(4, 'a');
+    queue.push(0, 'b');
+    queue.push(4, 'c');
+    assert_eq!(queue.len(), 3);
+
+    assert_eq!(queue.pop(), Some((0, 'b')));
+    queue.push(2, 'd');
+    assert_eq!(queue.pop(), Some((2, 'd')));
+    assert_eq!(queue.pop(), Some((4, 'c')));
+    queue.push(1, 'e');
+    assert_eq!(queue.pop(), Some((1, 'e')));
+    assert_eq!(queue.pop(), Some((4, 'a')));
+    assert_eq!(queue.pop(), None);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn dijkstra_test() {
+    let costs = [[1, 9, 1, 1], [1, 9, 1, 9], [1, 1, 1, 9], [9, 9, 1, 1]];
+    let mut best = [[usize::MAX; 4]; 4];
+    let mut queue = BucketQueue::new();
+    queue.push(0, (0, 0));
+
+    while let Some((cost, (x, y))) = queue.pop() {
+        if cost >= best[y][x] {
+            continue;
+        }
+        best[y][x] = cost;
+        let neighbours = [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)];
+        for (nx, ny) in neighbours.into_iter().filter(|&(nx, ny)| nx < 4 && ny < 4) {
+            queue.push(cost + costs[ny][nx], (nx, ny));
+        }
+    }
+
+    assert_eq!(best[3][3], 6);
+    assert_eq!(best[0][3], 7);
+}
@@ -0,0 +1,48 @@
+use aoc::util::math::*;
+
+#[test]
+fn gcd_lcm_test() {
+    assert_eq!(12_u64.gcd(18), 6);
+    assert_eq!((-12_i64).gcd(18), 6);
+    assert_eq!(7_u32.gcd(0), 7);
+    assert_eq!(4_usize.lcm(6), 12);
+    assert_eq!((-4_i32).lcm(6), 12);
+    assert_eq!(0_u128.lcm(5), 0);
+
+    let cycles = [12_u128, 18, 30, 7];
+    assert_eq!(cycles.iter().fold(1, |acc, &cycle| acc.lcm(cycle)), 1260);
+}
+
+#[test]
+fn mod_pow_test() {
+    assert_eq!(3_u64.mod_pow(200, 50), 1);
+    assert_eq!(2_i64.mod_pow(10, 1000), 24);
+    assert_eq!((-2_i64).mod_pow(3, 7), 6);
+    assert_eq!(5_u32.mod_pow(0, 1), 0);
+}
+
+#[test]
+fn extended_gcd_test() {
+    let (g, x, y) = 240_i64.extended_gcd(46);
+    assert_eq!(g, 2);
+    assert_eq!(240 * x + 46 * y, 2);
+}
+
+#[test]
+fn mod_inverse_test() {
+    assert_eq!(3_i64.mod_inverse(11), Some(4));
+    assert_eq!((-3_i64).mod_inverse(11), Some(7));
+    assert_eq!(6_i64.mod_inverse(9), None);
+}
+
+#[test]
+fn crt_test() {
+    assert_eq!(crt::<i64>(&[]), Some((0, 1)));
+    assert_eq!(crt(&[(2_i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    assert_eq!(crt(&[(2_i64, 4), (4, 6)]), Some((10, 12)));
+    assert_eq!(crt(&[(1_i64, 4), (2, 6)]), None);
+    assert_eq!(crt(&[(-1_i64, 5), (9, 10)]), Some((9, 10)));
+
+    let buses = [(0_i128, 7), (-1, 13), (-4, 59), (-6, 31), (-7, 19)];
+    assert_eq!(crt(&buses), Some((1_068_781, 3_162_341)));
+}
@@ -0,0 +1,24 @@
+use aoc::util::md5::*;
+
+fn hex(message: &str) -> String {
+    to_hex(hash(message.as_bytes()))
+}
+
+#[test]
+fn rfc_1321_test() {
+    assert_eq!(hex(""), "d41d8cd98f00b204e9800998ecf8427e");
+    assert_eq!(hex("abc"), "900150983cd24fb0d6963f7d28e17f72");
+    assert_eq!(hex("message digest"), "f96b697d7cb7938d525a2f31aaf161d0");
+    assert_eq!(
+        hex("12345678901234567890123456789012345678901234567890123456789012345678901234567890"),
+        "57edf4a22be3c955ac49da2e2107b67a"
+    );
+}
+
+#[test]
+fn padding_boundary_test() {
+    // 55 and 56 bytes straddle the point where padding needs a second block.
+    assert_eq!(hex(&"a".repeat(55)), "ef1772b6dff9a122358552954ad0df65");
+    assert_eq!(hex(&"a".repeat(56)), "3b0c8ac703f828b04c6c197006d17218");
+    assert_eq!(hex(&"a".repeat(64)), "014842d480b571495a4a0363793f7367");
+}
@@ -0,0 +1,14 @@
+use aoc::error::AocError;
+use aoc::util::parse::*;
+
+#[test]
+fn try_unsigned_test() {
+    assert_eq!("x=123,".try_unsigned::<u32>(), Ok(123));
+    assert_eq!("abc".try_unsigned::<u32>(), Err(AocError::new("Expected a number, found \"abc\"")));
+}
+
+#[test]
+fn try_signed_test() {
+    assert_eq!("y=-45".try_signed::<i32>(), Ok(-45));
+    assert!("".try_signed::<i32>().is_err());
+}
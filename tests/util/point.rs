@@ -0,0 +1,46 @@
+use aoc::error::AocError;
+use aoc::util::point::*;
+
+#[test]
+fn arithmetic_test() {
+    let a = Point::new(3, -2);
+    let b = Point::new(-1, 5);
+    assert_eq!(a + b, Point::new(2, 3));
+    assert_eq!(a - b, Point::new(4, -7));
+    assert_eq!(a * 3, Point::new(9, -6));
+    assert_eq!(-a, Point::new(-3, 2));
+
+    let mut c = a;
+    c += RIGHT;
+    c -= UP;
+    assert_eq!(c, Point::new(4, -1));
+}
+
+#[test]
+fn rotation_test() {
+    assert_eq!(ORTHOGONAL.map(Point::clockwise), [RIGHT, DOWN, LEFT, UP]);
+    assert_eq!(ORTHOGONAL.map(Point::counter_clockwise), [LEFT, UP, RIGHT, DOWN]);
+    assert_eq!(UP_RIGHT.clockwise(), DOWN_RIGHT);
+    assert_eq!(Point::new(2, 1).clockwise().counter_clockwise(), Point::new(2, 1));
+}
+
+#[test]
+fn distance_test() {
+    let a = Point::new(1, 1);
+    let b = Point::new(4, -1);
+    assert_eq!(a.manhattan_distance(&b), 5);
+    assert_eq!(a.chebyshev_distance(&b), 3);
+    assert_eq!(DIAGONAL.iter().map(|p| p.chebyshev_distance(&ORIGIN)).max(), Some(1));
+}
+
+#[test]
+fn parse_test() {
+    let arrows: Result<Vec<_>, _> = "^v<>".bytes().map(Point::try_from).collect();
+    let letters: Result<Vec<_>, _> = "UDLR".chars().map(Point::try_from).collect();
+    let compass: Result<Vec<_>, _> = "NESW".chars().map(Point::try_from).collect();
+    assert_eq!(arrows, Ok(vec![UP, DOWN, LEFT, RIGHT]));
+    assert_eq!(letters, Ok(vec![UP, DOWN, LEFT, RIGHT]));
+    assert_eq!(compass, Ok(vec![UP, RIGHT, DOWN, LEFT]));
+    assert_eq!(Point::try_from('x'), Err(AocError::new("Expected a direction, found 'x'")));
+    assert!(Point::try_from('→').is_err());
+}
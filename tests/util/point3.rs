@@ -0,0 +1,53 @@
+use aoc::util::point::Point;
+use aoc::util::point3::*;
+
+#[test]
+fn arithmetic_test() {
+    let position = Point3::new(19, 13, 30);
+    let velocity = Point3::new(-2, 1, -2);
+    assert_eq!(position + velocity * 5, Point3::new(9, 18, 20));
+    assert_eq!(position - position, Point3::new(0, 0, 0));
+    assert_eq!(-velocity, Point3::new(2, -1, 2));
+
+    let mut moving = position;
+    moving += velocity;
+    moving -= Point3::new(0, 0, 1);
+    assert_eq!(moving, Point3::new(17, 14, 27));
+    assert_eq!(moving.xy(), Point::new(17, 14));
+}
+
+#[test]
+fn distance_test() {
+    let a = Point3::new(1, 2, 3);
+    let b = Point3::new(-2, 6, 3);
+    assert_eq!(a.manhattan_distance(&b), 7);
+    assert_eq!(a.euclidean_squared(&b), 25);
+}
+
+#[test]
+fn cuboid_test() {
+    let brick = Cuboid::new(Point3::new(1, 2, 1), Point3::new(1, 0, 1));
+    assert_eq!(brick.min, Point3::new(1, 0, 1));
+    assert_eq!(brick.volume(), 3);
+    assert!(brick.contains(Point3::new(1, 1, 1)));
+    assert!(!brick.contains(Point3::new(1, 1, 2)));
+    assert_eq!(brick.project_xy(), (Point::new(1, 0), Point::new(1, 2)));
+    assert_eq!(brick.points().count(), 3);
+
+    let fallen = brick.translate(Point3::new(0, 0, -1));
+    assert_eq!(fallen, Cuboid::new(Point3::new(1, 0, 0), Point3::new(1, 2, 0)));
+}
+
+#[test]
+fn intersection_test() {
+    let big = Cuboid::new(Point3::new(0, 0, 0), Point3::new(9, 9, 9));
+    let small = Cuboid::new(Point3::new(8, 8, 8), Point3::new(12, 9, 9));
+    let apart = Cuboid::new(Point3::new(10, 0, 0), Point3::new(10, 9, 9));
+
+    let overlap = Cuboid::new(Point3::new(8, 8, 8), Point3::new(9, 9, 9));
+    assert_eq!(big.intersection(&small), Some(overlap));
+    assert_eq!(big.intersection(&apart), None);
+    assert!(small.intersects(&apart));
+    assert!(big.encloses(&overlap));
+    assert!(!big.encloses(&small));
+}
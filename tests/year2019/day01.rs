@@ -4,10 +4,19 @@ const EXAMPLE: &str = "100756";
 
 #[test]
 fn part1_test() {
-    assert_eq!(part1(EXAMPLE), 33583);
+    let input = try_parse(EXAMPLE).unwrap();
+    assert_eq!(part1(&input), 33583);
 }
 
 #[test]
 fn part2_test() {
-    assert_eq!(part2(EXAMPLE), 50346);
+    let input = try_parse(EXAMPLE).unwrap();
+    assert_eq!(part2(&input), 50346);
+}
+
+#[test]
+fn try_parse_test() {
+    let error = try_parse("12\nabc\n").unwrap_err();
+    assert_eq!((error.line, error.column), (Some(2), Some(1)));
+    assert_eq!(error.message, "Expected a number, found \"abc\"");
 }
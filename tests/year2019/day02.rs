@@ -4,10 +4,19 @@ const EXAMPLE: &str = "1,1,1,4,99,5,6,0,99";
 
 #[test]
 fn part1_test() {
-    assert_eq!(part1(EXAMPLE), 30);
+    let input = try_parse(EXAMPLE).unwrap();
+    assert_eq!(part1(&input), 30);
 }
 
 #[test]
 fn part2_test() {
-    assert_eq!(part2(EXAMPLE), 1202);
+    let input = try_parse(EXAMPLE).unwrap();
+    assert_eq!(part2(&input), 1202);
+}
+
+#[test]
+fn parse_error_test() {
+    let error = try_parse("1,0,0,3,x,99\n").err().unwrap();
+    assert_eq!((error.line, error.column), (Some(1), Some(9)));
+    assert_eq!(error.message, "Expected a number, found \"x\"");
 }
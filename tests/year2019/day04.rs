@@ -0,0 +1,17 @@
+use aoc::year2019::day04::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1("111111-111111"), 1);
+    assert_eq!(part1("223450-223450"), 0);
+    assert_eq!(part1("123789-123789"), 0);
+    // 111111, 111112 to 111119 and 111122 to 111129.
+    assert_eq!(part1("111111-111130"), 17);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2("112233-112233"), 1);
+    assert_eq!(part2("123444-123444"), 0);
+    assert_eq!(part2("111122-111122"), 1);
+}
@@ -4,15 +4,18 @@ const EXAMPLE: &str = "3,0,4,0,99";
 
 #[test]
 fn part1_test() {
-    assert_eq!(part1(EXAMPLE), 1);
+    let input = try_parse(EXAMPLE).unwrap();
+    assert_eq!(part1(&input), 1);
 }
 
 #[test]
 fn part1_test_2() {
-    assert_eq!(part1("1101,100,-1,4,0"), 1);
+    let input = try_parse("1101,100,-1,4,0").unwrap();
+    assert_eq!(part1(&input), 1);
 }
 
 #[test]
 fn part2_test() {
-    assert_eq!(part2(EXAMPLE), 0);
+    let input = try_parse(EXAMPLE).unwrap();
+    assert_eq!(part2(&input), 0);
 }
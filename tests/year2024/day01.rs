@@ -10,10 +10,12 @@ const EXAMPLE: &str = "\
 
 #[test]
 fn part1_test() {
-    assert_eq!(part1(EXAMPLE), 11);
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 11);
 }
 
 #[test]
 fn part2_test() {
-    assert_eq!(part2(EXAMPLE), 31);
+    let input = parse(EXAMPLE);
+    assert_eq!(part2(&input), 31);
 }
@@ -0,0 +1,23 @@
+use aoc::year2024::day06::*;
+
+const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(EXAMPLE), 41);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(EXAMPLE), 6);
+}
@@ -0,0 +1,25 @@
+use aoc::year2024::day08::*;
+
+const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(EXAMPLE), 14);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(EXAMPLE), 34);
+}
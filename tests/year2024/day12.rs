@@ -0,0 +1,23 @@
+use aoc::year2024::day12::*;
+
+const EXAMPLE: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(EXAMPLE), 1930);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(EXAMPLE), 1206);
+}
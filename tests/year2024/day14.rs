@@ -0,0 +1,40 @@
+use aoc::year2024::day14::*;
+
+/// Robot that will be at `(x, y)` after `seconds`, moving with velocity `(vx, vy)` in the
+/// 101 by 103 space.
+fn robot((x, y): (i32, i32), (vx, vy): (i32, i32), seconds: i32) -> String {
+    let px = (x - vx * seconds).rem_euclid(101);
+    let py = (y - vy * seconds).rem_euclid(103);
+    format!("p={px},{py} v={vx},{vy}")
+}
+
+#[test]
+fn part1_test() {
+    let input = [
+        // Stationary robots, 2 top left, 1 top right, 3 bottom left, 1 bottom right and 1 on
+        // the middle column that doesn't count.
+        robot((0, 0), (0, 0), 0),
+        robot((49, 50), (0, 0), 0),
+        robot((100, 0), (0, 0), 0),
+        robot((0, 102), (0, 0), 0),
+        robot((10, 60), (0, 0), 0),
+        robot((20, 70), (0, 0), 0),
+        robot((100, 102), (0, 0), 0),
+        robot((50, 10), (0, 0), 0),
+        // Wraps around to the top left after 100 seconds.
+        "p=2,4 v=2,-3".to_owned(),
+    ];
+    assert_eq!(part1(&input.join("\n")), 3 * 3);
+}
+
+#[test]
+fn part2_test() {
+    // Triangle 6 rows tall with its top at (50, 40), drawn by robots all moving at different
+    // speeds so that it only appears after 7 seconds.
+    let input: Vec<_> = (0..6)
+        .flat_map(|i| [(50 - i, 40 + i), (50 + i, 40 + i)])
+        .enumerate()
+        .map(|(k, target)| robot(target, (k as i32 + 1, 2 * k as i32 + 3), 7))
+        .collect();
+    assert_eq!(part2(&input.join("\n")), 7);
+}
@@ -0,0 +1,28 @@
+use aoc::year2024::day16::*;
+
+const EXAMPLE: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(EXAMPLE), 7036);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(EXAMPLE), 45);
+}
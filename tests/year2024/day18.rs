@@ -0,0 +1,22 @@
+use aoc::year2024::day18::*;
+
+/// Falling bytes filling row `y` of the 71 by 71 memory space, apart from column `gap`.
+fn wall(y: usize, gap: Option<usize>) -> Vec<String> {
+    (0..71).filter(|&x| Some(x) != gap).map(|x| format!("{x},{y}")).collect()
+}
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1("5,5"), 140);
+
+    // Zig zag right through the gap at the end of the first wall then back left to the gap at
+    // the start of the second.
+    let input = [wall(5, Some(70)), wall(10, Some(0))].concat().join("\n");
+    assert_eq!(part1(&input), 280);
+}
+
+#[test]
+fn part2_test() {
+    let input = [wall(5, Some(70)), wall(10, None)].concat().join("\n");
+    assert_eq!(part2(&input), "70,10");
+}
@@ -0,0 +1,24 @@
+use aoc::year2024::day20::*;
+
+/// Track running 60 picoseconds right along the top, down and 60 back along the bottom, with
+/// the end just below the start through a single wall. The puzzle example never saves the 100
+/// picoseconds that are counted.
+fn hairpin() -> String {
+    let wall = "#".repeat(63);
+    let top = format!("#S{}#", ".".repeat(60));
+    let middle = format!("#{}.#", "#".repeat(60));
+    let bottom = format!("#E{}#", ".".repeat(60));
+    [wall.as_str(), &top, &middle, &bottom, &wall].join("\n")
+}
+
+#[test]
+fn part1_test() {
+    // Cheating straight down from any of the first 11 positions on the top saves at least 100.
+    assert_eq!(part1(&hairpin()), 11);
+}
+
+#[test]
+fn part2_test() {
+    // Cheating between any of the first 11 positions on the top and bottom saves at least 100.
+    assert_eq!(part2(&hairpin()), 121);
+}
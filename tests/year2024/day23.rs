@@ -35,13 +35,15 @@ td-yn";
 
 #[test]
 fn part1_test() {
-    assert_eq!(part1(EXAMPLE), 7);
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 7);
 }
 
 
 
 #[test]
 fn part2_test() {
-    assert_eq!(part2(EXAMPLE), "co,de,ka,ta");
+    let input = parse(EXAMPLE);
+    assert_eq!(part2(&input), "co,de,ka,ta");
 }
 
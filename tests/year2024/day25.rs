@@ -1,3 +1,4 @@
+use aoc::solution::Answer;
 use aoc::year2024::day25::*;
 
 const EXAMPLE: &str = "#####
@@ -45,6 +46,11 @@ fn part1_test() {
     assert_eq!(part1(EXAMPLE), 3);
 }
 
+#[test]
+fn part2_test() {
+    assert_eq!(part2(EXAMPLE), Answer::Empty);
+}
+
 
 
 // #[test]